@@ -0,0 +1,253 @@
+// wordle_bench
+//  Plays a strategy against every answer (or a seeded sample) on the
+//  engine alone, then reports how it did as a table or as JSON.
+//
+//  --strategy <name>   entropy (default) or first
+//  --opener <word>     fixed first guess instead of the strategy's own
+//  --sample <n>        play n answers instead of all of them
+//  --seed <n>          which sample to draw (default 0)
+//  --attempts <n>      guesses allowed per game (default 6, up to 20)
+//  --json              print JSON instead of the table
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use wordle_nova::{Board, BoardStatus, Dictionary, PrErr, Recommender, Rules, Solver, WordSource};
+
+// Strategy
+//  Picks the next guess for a board; the board's rows are the only input.
+trait Strategy {
+    fn name(&self) -> &'static str;
+    fn guess(&mut self, board: &Board) -> Option<String>;
+}
+
+// Highest expected information, leaning toward possible answers.
+// Guesses depend only on the rows so far, so they are remembered per
+// history and each position in the game tree is ranked once.
+struct Entropy {
+    memo: HashMap<String, String>
+} impl Strategy for Entropy {
+    fn name(&self) -> &'static str {
+        "entropy"
+    }
+
+    fn guess(&mut self, board: &Board) -> Option<String> {
+        let key = history(board);
+        if let Some(guess) = self.memo.get(&key) {
+            return Some(guess.clone());
+        }
+
+        let recommender = Recommender::from_board(board);
+        let guess = match recommender.candidates() {
+            []                          => return None,
+            [only] | [only, _]          => only.to_string(),
+            _                           => recommender.top(1).pop()?.word
+        };
+
+        self.memo.insert(key, guess.clone());
+        Some(guess)
+    }
+}
+
+// First word, in list order, that could still be the answer
+struct First;
+
+impl Strategy for First {
+    fn name(&self) -> &'static str {
+        "first"
+    }
+
+    fn guess(&mut self, board: &Board) -> Option<String> {
+        Solver::from_board(board).candidates().first().map(|w| w.to_string())
+    }
+}
+
+fn history(board: &Board) -> String {
+    board.guesses.iter().zip(board.correction.iter())
+        .filter(|(_, feedback)| feedback.is_scored())
+        .map(|(guess, feedback)| format!("{}:{} ", guess.iter().collect::<String>(), feedback.pattern()))
+        .collect()
+}
+
+// Options
+struct Options {
+    strategy: Box<dyn Strategy>,
+    opener: Option<String>,
+    sample: Option<usize>,
+    seed: u64,
+    rules: Rules,
+    json: bool
+} impl Options {
+    fn from_args<I: Iterator<Item = String>>(mut args: I) -> PrErr<Options> {
+        let mut options = Options {
+            strategy: Box::new(Entropy { memo: HashMap::new() }),
+            opener: None,
+            sample: None,
+            seed: 0,
+            rules: Rules::default(),
+            json: false
+        };
+
+        while let Some(arg) = args.next() {
+            if arg == "--json" {
+                options.json = true;
+                continue;
+            }
+
+            let value = args.next().ok_or(format!("main: {} needs a value", arg))?;
+            let number = |value: &str| value.parse::<u64>().map_err(|e| format!("main: Bad {} {}: {}", arg, value, e));
+
+            match arg.as_str() {
+                "--strategy"    => options.strategy = match value.as_str() {
+                    "entropy"   => Box::new(Entropy { memo: HashMap::new() }),
+                    "first"     => Box::new(First),
+                    _           => return Err(format!("main: Unknown strategy {}", value))
+                },
+                "--opener"      => options.opener = Some(value.to_ascii_uppercase()),
+                "--sample"      => options.sample = Some(number(&value)? as usize),
+                "--seed"        => options.seed = number(&value)?,
+                "--attempts"    => options.rules = Rules::new(options.rules.length, number(&value)? as usize)?,
+                _               => return Err(format!("main: Unknown option {}", arg))
+            };
+        }
+
+        Ok(options)
+    }
+}
+
+// Plays one game; the number of guesses on a win, None on a loss
+fn play(options: &mut Options, dictionary: &Dictionary, answer: &str) -> PrErr<Option<usize>> {
+    let mut board = Board::new(answer, options.rules)?.with_dictionary(dictionary.clone());
+
+    while board.is_playing() {
+        let guess = match (&options.opener, board.status.clone()) {
+            (Some(opener), BoardStatus::NotStarted) => opener.clone(),
+            _                                       => match options.strategy.guess(&board) {
+                Some(g) => g,
+                None    => return Ok(None)
+            }
+        };
+
+        board.enter_word(&guess).map_err(|e| format!("{} guessed {} against {}: {}", options.strategy.name(), guess, answer, e))?;
+    }
+
+    Ok(match board.status {
+        BoardStatus::Win(n) => Some(n + 1),
+        _                   => None
+    })
+}
+
+// Report
+struct Report {
+    strategy: &'static str,
+    attempts: usize,
+    results: Vec<(String, Option<usize>)>
+} impl Report {
+    fn wins(&self) -> impl Iterator<Item = usize> + '_ {
+        self.results.iter().filter_map(|(_, n)| *n)
+    }
+
+    fn failures(&self) -> usize {
+        self.results.iter().filter(|(_, n)| n.is_none()).count()
+    }
+
+    fn average(&self) -> f64 {
+        let wins: Vec<usize> = self.wins().collect();
+        match wins.is_empty() {
+            true    => 0.0,
+            false   => wins.iter().sum::<usize>() as f64 / wins.len() as f64
+        }
+    }
+
+    // distribution[n - 1] games won in n guesses
+    fn distribution(&self) -> Vec<usize> {
+        let mut counts = vec![0; self.attempts];
+        self.wins().for_each(|n| counts[n - 1] += 1);
+        counts
+    }
+
+    // Losses first, then the longest wins
+    fn worst(&self, n: usize) -> Vec<&(String, Option<usize>)> {
+        let mut worst: Vec<&(String, Option<usize>)> = self.results.iter().collect();
+        worst.sort_by_key(|(word, guesses)| (guesses.map_or(0, |g| self.attempts + 1 - g), word.clone()));
+        worst.truncate(n);
+        worst
+    }
+
+    fn table(&self) -> String {
+        let mut out = String::new();
+        let distribution = self.distribution();
+        let widest = distribution.iter().copied().max().unwrap_or(0).max(self.failures()).max(1);
+        let bar = |n: usize| "#".repeat((n * 40).div_ceil(widest));
+
+        let _ = writeln!(out, "Strategy   {}", self.strategy);
+        let _ = writeln!(out, "Games      {}", self.results.len());
+        let _ = writeln!(out, "Average    {:.3} guesses (wins only)", self.average());
+        let _ = writeln!(out, "Failures   {}", self.failures());
+        let _ = writeln!(out, "\nDistribution");
+        for (i, n) in distribution.iter().enumerate() {
+            let _ = writeln!(out, "  {} | {:>5} | {}", i + 1, n, bar(*n));
+        }
+        let _ = writeln!(out, "  X | {:>5} | {}", self.failures(), bar(self.failures()));
+        let _ = writeln!(out, "\nWorst");
+        for (word, guesses) in self.worst(10) {
+            let _ = writeln!(out, "  {}  {}", word, guesses.map_or(String::from("X"), |g| g.to_string()));
+        }
+
+        out
+    }
+
+    fn json(&self) -> String {
+        let list = |items: Vec<String>| items.join(", ");
+
+        format!(
+            "{{\"strategy\": \"{}\", \"games\": {}, \"wins\": {}, \"failures\": {}, \"average\": {:.4}, \"distribution\": [{}], \"worst\": [{}]}}",
+            self.strategy,
+            self.results.len(),
+            self.wins().count(),
+            self.failures(),
+            self.average(),
+            list(self.distribution().iter().map(|n| n.to_string()).collect()),
+            list(self.worst(10).iter().map(
+                |(word, guesses)| format!("{{\"word\": \"{}\", \"guesses\": {}}}", word, guesses.map_or(String::from("null"), |g| g.to_string()))
+            ).collect())
+        )
+    }
+}
+
+// Main
+fn main() {
+    let mut options = match Options::from_args(std::env::args().skip(1)) {
+        Ok(o)   => o,
+        Err(e)  => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
+
+    let dictionary = Dictionary::bundled();
+    let answers = match options.sample {
+        Some(n) => WordSource::sample(&dictionary.answers, n, options.seed),
+        None    => dictionary.answers.words().to_vec()
+    };
+
+    let mut report = Report { strategy: options.strategy.name(), attempts: options.rules.attempts, results: Vec::new() };
+    for (i, answer) in answers.iter().enumerate() {
+        match play(&mut options, &dictionary, answer) {
+            Ok(guesses) => report.results.push((answer.clone(), guesses)),
+            Err(e)      => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        };
+
+        if (i + 1) % 500 == 0 {
+            eprintln!("{}/{} games", i + 1, answers.len());
+        }
+    }
+
+    match options.json {
+        true    => println!("{}", report.json()),
+        false   => print!("{}", report.table())
+    };
+}
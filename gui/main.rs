@@ -0,0 +1,1304 @@
+// Modules
+mod multi;
+
+// Imports
+use std::path::PathBuf;
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use wordle_nova::{
+    Board, BoardStatus, Challenge, GameMode, GuessFeedback, LaunchOptions, PrErr, Race, RaceClient, Recommender,
+    Rgb, Rules, Speedrun, Stats, Stopwatch, TileState, ToPlayer, CONTRAST_CORRECT, KEYBOARD_LAYOUT, THEMES
+};
+
+use druid::{
+    Data, Lens, Env, Event, KbKey, TimerToken, Selector, Target,
+    AppLauncher, Application, WindowDesc, PlatformError,
+    Widget, WidgetExt,
+    Rect, Color, FontDescriptor, FontFamily, FontWeight,
+    RenderContext, PaintCtx, EventCtx
+};
+
+use druid::piet::{Text, TextLayoutBuilder};
+
+use druid::widget::{
+    Label, Flex, Painter, ZStack, Controller, Either, SizedBox
+};
+
+// AppState
+//  druid needs Data on the root type; the engine crate stays GUI-free,
+//  so the Board is wrapped here and compared by value.
+#[derive(Clone, Data, Lens)]
+struct AppState {
+    #[data(eq)] board: Board,
+    notice: String, // shown over the current row, eg. a rejected guess
+    clock: String, // time shown while playing, refreshed by the Ticker
+    #[data(eq)] speedrun: Option<Speedrun>,
+    #[data(eq)] race: Option<Race>, // as last heard from the race host
+    #[data(ignore)] race_client: Option<Arc<RaceClient>>,
+
+    #[data(eq)] stats: Stats,
+    #[data(ignore)] stats_path: Option<PathBuf>, // None when stats can't be saved
+    show_stats: bool,
+
+    #[data(ignore)] save_path: Option<PathBuf> // None when the game can't be saved
+} impl AppState {
+    fn create(
+        board: Board, speedrun: Option<Speedrun>, race: Option<(RaceClient, Race)>,
+        stats: Stats, stats_path: Option<PathBuf>, save_path: Option<PathBuf>
+    ) -> AppState {
+        let (race_client, race) = match race {
+            Some((client, race))    => (Some(Arc::new(client)), Some(race)),
+            None                    => (None, None)
+        };
+
+        AppState {
+            board,
+            notice: String::new(),
+            clock: String::new(),
+            speedrun,
+            race,
+            race_client,
+
+            stats,
+            stats_path,
+            show_stats: false,
+
+            save_path
+        }
+    }
+}
+
+// Actions
+//  Shared by the on-screen tiles and buttons and the physical keyboard,
+//  so both ways of playing go through the same code.
+fn type_letter(data: &mut AppState, c: char) {
+    data.board.push_letter(c);
+    data.notice.clear();
+}
+
+fn delete_letter(data: &mut AppState) {
+    data.board.pop_letter();
+    data.notice.clear();
+}
+
+// Enter on a finished board starts the next one
+fn submit(data: &mut AppState) {
+    if !data.board.is_playing() {
+        return new_game(data);
+    }
+    if data.race.as_ref().is_some_and(|race| !race.started) {
+        data.notice = String::from("Waiting for the race to start");
+        return;
+    }
+
+    // A guess made too late is refused but still ends the game
+    match data.board.submit_row() {
+        Ok(feedback)    => {
+            data.notice.clear();
+            send_row(data, &feedback);
+        },
+        Err(e)          => {
+            data.notice = e.to_string();
+            if data.board.is_playing() {
+                return;
+            }
+        }
+    };
+
+    save_game(data);
+    if !data.board.is_playing() {
+        end_game(data);
+    }
+}
+
+// A speedrun moves on to its next word; anything else is over
+fn end_game(data: &mut AppState) {
+    if let Some(run) = &mut data.speedrun {
+        match run.next(&data.board) {
+            Ok(Some(board)) => {
+                data.notice = format!("Word {} in {}", run.splits.len(), Stopwatch(run.splits.last().copied().unwrap_or_default()));
+                data.board = board;
+                return;
+            },
+            Ok(None)        => (),
+            Err(e)          => {
+                data.notice = e;
+                return;
+            }
+        };
+    }
+
+    record_game(data);
+}
+
+// Passes the colours of a guess on to the other racers
+fn send_row(data: &mut AppState, feedback: &GuessFeedback) {
+    if let (Some(client), Some(race)) = (&data.race_client, &data.race) {
+        if !race.over {
+            if let Err(e) = client.send_row(feedback) {
+                data.notice = e;
+            }
+        }
+    }
+}
+
+// After every guess, so closing the window never loses a game.
+// Speedrun and race boards aren't saved: resumed alone they'd be a free game.
+fn save_game(data: &mut AppState) {
+    if data.speedrun.is_some() || data.race.is_some() {
+        return;
+    }
+
+    if let Some(path) = &data.save_path {
+        if let Err(e) = data.board.save(path) {
+            data.notice = e;
+        }
+    }
+}
+
+// Counts the game (or run) that just ended and brings up the stats
+fn record_game(data: &mut AppState) {
+    let recorded = match &data.speedrun {
+        Some(run)   => data.stats.record_speedrun(run),
+        None        => data.stats.record(&data.board)
+    };
+    if recorded {
+        if let Some(path) = &data.stats_path {
+            if let Err(e) = data.stats.save(path) {
+                data.notice = e;
+            }
+        }
+    }
+
+    data.show_stats = true;
+}
+
+fn new_game(data: &mut AppState) {
+    if data.race.is_some() {
+        data.notice = String::from("A race is a single word");
+        return;
+    }
+
+    data.show_stats = false;
+    if let Some(run) = &data.speedrun {
+        let run = run.again();
+        return new_speedrun(data, run);
+    }
+
+    data.notice = match data.board.reset() {
+        Ok(_)   => String::new(),
+        Err(e)  => e.to_string()
+    };
+
+    save_game(data);
+}
+
+// A finished run is followed by another of the same length
+fn new_speedrun(data: &mut AppState, run: PrErr<Speedrun>) {
+    let started = run.and_then(|run| run.board().map(|board| (run, board)));
+    data.notice = match started {
+        Ok((run, mut board))    => {
+            keep_settings(&mut board, &data.board);
+            data.board = board;
+            data.speedrun = Some(run);
+            String::new()
+        },
+        Err(e)                  => e
+    };
+}
+
+// A new board for the same player keeps their settings
+fn keep_settings(board: &mut Board, from: &Board) {
+    board.hard_mode = from.hard_mode;
+    board.high_contrast = from.high_contrast;
+    board.gui_theme_no = from.gui_theme_no;
+}
+
+// Refreshes the clock text and ends a blitz game that ran out of time
+fn tick(data: &mut AppState, now: Instant) {
+    if data.board.check_time(now) {
+        data.notice = String::from("Out of time");
+        save_game(data);
+        end_game(data);
+    }
+
+    data.clock = match (&data.speedrun, data.board.clock.remaining(now)) {
+        (Some(run), _)      => format!("Word {}/{}   {}", run.word(), run.answers.len(), Stopwatch(run.elapsed(&data.board, now))),
+        (None, Some(left))  => format!("{} left", Stopwatch(left)),
+        (None, None)        => Stopwatch(data.board.clock.elapsed(now)).to_string()
+    };
+}
+
+fn hint(data: &mut AppState) {
+    if data.race.is_some() {
+        data.notice = String::from("No hints in a race");
+        return;
+    }
+
+    if data.board.is_playing() {
+        match data.board.quick_hint() {
+            Ok(h)   => {
+                data.notice = h.to_string();
+                save_game(data);
+            },
+            Err(e)  => data.notice = e
+        };
+    }
+}
+
+// Copies the emoji grid for pasting into chat
+fn share(data: &mut AppState) {
+    data.notice = match data.board.share() {
+        Ok(text)    => {
+            Application::global().clipboard().put_string(text);
+            String::from("Copied to clipboard")
+        },
+        Err(_)      => String::from("Finish the game to share it")
+    };
+}
+
+// Turns the word typed into the current row into a code for someone
+// else to play (with --challenge), under this game's rules
+fn challenge(data: &mut AppState) {
+    if !data.board.is_playing() {
+        data.notice = String::from("Start a new game to set a challenge");
+        return;
+    }
+
+    let word: String = data.board.guesses[data.board.gui_current_loc[0]].iter().collect();
+    data.notice = match Challenge::new(&data.board.dictionary, &word, data.board.rules, data.board.hard_mode) {
+        Ok(challenge)                   => {
+            let code = challenge.code();
+            Application::global().clipboard().put_string(&code);
+            data.board.clear_row();
+            format!("Challenge {} copied to clipboard", code)
+        },
+        Err(_) if word.contains('·')    => String::from("Type the word to challenge with first"),
+        Err(e)                          => e
+    };
+}
+
+fn move_cursor(data: &mut AppState, right: bool) {
+    let [row, col] = data.board.gui_current_loc;
+    match right {
+        true    => data.board.select(row, col + 1),
+        false   => data.board.select(row, col.saturating_sub(1))
+    };
+}
+
+// Input
+//  What the physical keyboard can do, for every window's state.
+trait Input {
+    fn type_letter(&mut self, c: char);
+    fn delete_letter(&mut self);
+    fn submit(&mut self);
+    fn move_cursor(&mut self, right: bool);
+    fn dismiss(&mut self); // close whatever is shown over the board
+}
+
+impl Input for AppState {
+    fn type_letter(&mut self, c: char) {
+        type_letter(self, c);
+    }
+
+    fn delete_letter(&mut self) {
+        delete_letter(self);
+    }
+
+    fn submit(&mut self) {
+        submit(self);
+    }
+
+    fn move_cursor(&mut self, right: bool) {
+        move_cursor(self, right);
+    }
+
+    fn dismiss(&mut self) {
+        self.show_stats = false;
+    }
+}
+
+// KeyboardInput
+//  Letters type into the current row, Backspace deletes, Enter submits
+//  and the arrow keys move the cursor along the row.
+struct KeyboardInput;
+
+impl<T: Data + Input, W: Widget<T>> Controller<T, W> for KeyboardInput {
+    fn event(&mut self, child: &mut W, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        match event {
+            Event::WindowConnected => ctx.request_focus(),
+            Event::KeyDown(key) if !(key.mods.ctrl() || key.mods.alt() || key.mods.meta()) => {
+                let handled = match &key.key {
+                    KbKey::Character(s) => match s.chars().next() {
+                        Some(c) if s.len() == 1 && c.is_ascii_alphabetic() => { data.type_letter(c); true },
+                        _                                                   => false
+                    },
+                    KbKey::Backspace    => { data.delete_letter(); true },
+                    KbKey::Enter        => { data.submit(); true },
+                    KbKey::ArrowLeft    => { data.move_cursor(false); true },
+                    KbKey::ArrowRight   => { data.move_cursor(true); true },
+                    KbKey::Escape       => { data.dismiss(); true },
+                    _                   => false
+                };
+
+                if handled {
+                    ctx.set_handled();
+                    return;
+                }
+            }
+            _ => ()
+        };
+
+        child.event(ctx, event, data, env);
+    }
+}
+
+// Ticker
+//  Wakes the window a few times a second so the clock keeps moving
+//  without any input.
+const TICK: Duration = Duration::from_millis(100);
+
+struct Ticker {
+    timer: TimerToken
+}
+
+impl<W: Widget<AppState>> Controller<AppState, W> for Ticker {
+    fn event(&mut self, child: &mut W, ctx: &mut EventCtx, event: &Event, data: &mut AppState, env: &Env) {
+        match event {
+            Event::WindowConnected                          => self.timer = ctx.request_timer(TICK),
+            Event::Timer(token) if *token == self.timer     => {
+                tick(data, Instant::now());
+                self.timer = ctx.request_timer(TICK);
+                ctx.set_handled();
+                return;
+            },
+            _                                               => ()
+        };
+
+        child.event(ctx, event, data, env);
+    }
+}
+
+// RaceUpdates
+//  Messages from the race host are read on a thread of their own and
+//  come in here as commands, to be applied to the race on screen.
+const RACE_MESSAGE: Selector<PrErr<ToPlayer>> = Selector::new("wordle-beta.race-message");
+
+struct RaceUpdates;
+
+impl<W: Widget<AppState>> Controller<AppState, W> for RaceUpdates {
+    fn event(&mut self, child: &mut W, ctx: &mut EventCtx, event: &Event, data: &mut AppState, env: &Env) {
+        if let Event::Command(cmd) = event {
+            if let (Some(message), Some(race)) = (cmd.get(RACE_MESSAGE), &mut data.race) {
+                match message {
+                    Ok(m)               => race.apply(m),
+                    Err(_) if race.over => (), // the host closes up once the race is over
+                    Err(e)              => data.notice = e.clone()
+                };
+
+                ctx.set_handled();
+                return;
+            }
+        }
+
+        child.event(ctx, event, data, env);
+    }
+}
+
+// Main
+fn main() -> Result<(), PlatformError> {
+    let options = match LaunchOptions::from_args(std::env::args().skip(1)) {
+        Ok(o)   => o,
+        Err(e)  => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+
+    // An unreadable save is reported and then replaced by the next one
+    let save_path = Board::save_path().map_err(|e| eprintln!("Games won't be saved: {}", e)).ok();
+    let saved = match save_path.as_ref().map(|path| Board::resume(path, options.dictionary.clone())) {
+        Some(Ok(saved)) => saved,
+        Some(Err(e))    => { eprintln!("Saved game not resumed: {}", e); None },
+        None            => None
+    };
+
+    // Several boards get their own window; only the saved settings carry over
+    if options.boards > 1 {
+        return multi::run(options, saved);
+    }
+
+    // Speedruns and races aren't saved, so there's nothing to resume but the settings
+    let (messages, inbox) = mpsc::channel();
+    let started = match (options.speedrun, options.race.is_some()) {
+        (Some(words), _)    => options.speedrun().and_then(|run| {
+            println!("Speedrun seed {} (replay with --speedrun {} --seed {})", run.seed, words, run.seed);
+
+            let mut board = run.board()?;
+            if let Some(saved) = &saved {
+                keep_settings(&mut board, saved);
+            }
+            Ok((board, Some(run), None))
+        }),
+        (None, true)        => options.race(move |message| { let _ = messages.send(message); }).map(|(client, race, mut board)| {
+            if let Some(saved) = &saved {
+                keep_settings(&mut board, saved);
+            }
+            (board, None, Some((client, race)))
+        }),
+        (None, false)       => options.resume(saved).map(|board| (board, None, None))
+    };
+    let (board, speedrun, race) = match started {
+        Ok(s)   => s,
+        Err(e)  => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+    if let (Some(seed), None) = (board.seed, &race) {
+        println!("Game seed {} (replay with --seed {})", seed, seed);
+    }
+
+    // A stats file that can't be read is left alone rather than overwritten
+    let (stats, stats_path) = match Stats::path().and_then(|path| Stats::load(&path).map(|stats| (stats, path))) {
+        Ok((stats, path))   => (stats, Some(path)),
+        Err(e)              => {
+            eprintln!("Stats won't be saved: {}", e);
+            (Stats::default(), None)
+        }
+    };
+
+    let racing = race.is_some();
+    let game_window = WindowDesc::new(board_ui(board.rules, racing))
+        .title(
+            |data: &AppState, _env: &Env| match (data.board.mode, data.board.provider.remaining(), data.board.seed) {
+                _ if data.speedrun.is_some()        => String::from("Wordle_Beta - Speedrun"),
+                _ if data.race.is_some()            => String::from("Wordle_Beta - Race"),
+                (GameMode::Daily(n), _, _)          => format!("Wordle_Beta - Daily #{}", n),
+                (_, Some(_), _)                     => String::from("Wordle_Beta - Absurdle"),
+                // so any game in a session can be replayed with --seed
+                (GameMode::FreePlay, None, Some(s)) => format!("Wordle_Beta - Seed {}", s),
+                (GameMode::FreePlay, None, None)    => String::from("Wordle_Beta")
+            }
+        )
+        .window_size(window_size(board.rules, racing));
+
+    // Ranking the opening suggestion takes a while, so it's done while the first guess
+    // is typed rather than when a hint is asked for
+    let dictionary = board.dictionary.clone();
+    thread::spawn(move || Recommender::opening(&dictionary));
+
+    let game = AppState::create(board, speedrun, race, stats, stats_path, save_path);
+
+    let launcher = AppLauncher::with_window(game_window).log_to_console();
+    let sink = launcher.get_external_handle();
+    thread::spawn(move || {
+        for message in inbox {
+            if sink.submit_command(RACE_MESSAGE, message, Target::Auto).is_err() {
+                return;
+            }
+        }
+    });
+
+    launcher.launch(game)
+}
+
+// UI
+const SIZE: f64 = 100.0 / 2.0;
+const SPACE: f64 = 20.0 / 2.0;
+// The engine's palettes, as druid colours
+const THEMATICS: [[Color; 6]; THEMES.len()] = thematics();
+
+const fn thematics() -> [[Color; 6]; THEMES.len()] {
+    let mut out = [[Color::BLACK; 6]; THEMES.len()];
+    let mut theme = 0;
+    while theme < THEMES.len() {
+        let mut i = 0;
+        while i < 6 {
+            out[theme][i] = rgb(THEMES[theme][i]);
+            i += 1;
+        }
+        theme += 1;
+    }
+
+    out
+}
+
+const fn rgb((r, g, b): Rgb) -> Color {
+    Color::rgb8(r, g, b)
+}
+
+fn tile_colour(board: &Board, state: TileState) -> Color {
+    rgb(wordle_nova::tile_colour(board.gui_theme_no, board.high_contrast, state))
+}
+
+// Grid size, in pixels, for the board's word length and attempts
+fn grid_width(rules: Rules) -> f64 {
+    SIZE * (rules.length as f64) + SPACE * (rules.length as f64)
+}
+
+fn grid_height(rules: Rules) -> f64 {
+    SIZE * 1.5 * (rules.attempts as f64) + SPACE * (rules.attempts as f64 - 1.0) + SPACE * 2.0
+}
+
+// Room for the opponents' rows beside the game, when in a race
+fn race_width(racing: bool) -> f64 {
+    match racing {
+        true    => RACE_WIDTH + SPACE * 4.0,
+        false   => 0.0
+    }
+}
+
+fn window_size(rules: Rules, racing: bool) -> (f64, f64) {
+    (
+        1500.0 + grid_width(rules) - grid_width(Rules::default()) + race_width(racing),
+        grid_height(rules).max(grid_height(Rules::default())) + 200.0
+    )
+}
+
+fn board_ui(rules: Rules, racing: bool) -> impl Widget<AppState> {
+    let mont: FontDescriptor = FontDescriptor::new(
+            FontFamily::new_unchecked("Montserrat")
+        )
+        .with_weight(FontWeight::SEMI_BOLD)
+        .with_size(20.0);
+
+    let mut guess_ui: Flex<AppState> = Flex::column()
+        .with_flex_spacer(SPACE);
+
+    let mut keyboard: Flex<AppState> = Flex::column()
+        .with_flex_spacer(SPACE);
+
+    let mut theme_switch: Flex<AppState> = Flex::column()
+        .with_flex_spacer(SPACE);
+
+    for i in 0..rules.attempts {
+        let mut guess_ui_r: Flex<AppState> = Flex::row();
+
+        for j in 0..rules.length {
+            guess_ui_r.add_child(
+                Flex::column()
+                    .with_flex_spacer(SPACE / 4.0)
+                    .with_child(
+                        Flex::row()
+                            .with_flex_spacer(SPACE / 8.0)
+                            .with_child(
+                                Label::new(
+                                    {
+                                        let row = i; let col = j;
+                                        move |data: &AppState, _env: &Env| {
+                                            String::from(data.board.guesses[row][col])
+                                        }
+                                    }
+                                )
+                                .with_font(mont.clone())
+                            )
+                            .with_child(
+                                Label::new(
+                                    {
+                                        let row = i; let col = j;
+                                        move |data: &AppState, _env: &Env| {
+                                            // subscript digit, only once a letter is known to repeat
+                                            match data.board.correction[row].tile(col).answer_count {
+                                                Some(n) if n > 1    => char::from_u32(0x2080 + n as u32).map_or(String::new(), String::from),
+                                                _                   => String::from(" ")
+                                            }
+                                        }
+                                    }
+                                )
+                                .with_font(mont.clone())
+                            )
+                            .with_flex_spacer(SPACE / 8.0)
+                            .fix_width(SIZE)
+                    )
+                    .with_flex_spacer(SPACE / 4.0)
+                    .fix_height(SIZE * 1.5)
+                    .background(
+                        Painter::new(
+                            {
+                                let row = i; let col = j;
+                                move |ctx: &mut PaintCtx, data: &AppState, _env: &Env| {
+                                    let rnd = ctx.size().to_rounded_rect(SIZE / 4.0);
+                                    ctx.fill( rnd, &tile_colour(&data.board, data.board.correction[row].state(col)) );
+
+                                    match data.board.status {
+                                        BoardStatus::NotStarted | BoardStatus::OnGoing(_) => {
+                                            if data.board.gui_current_loc == [row, col] {
+                                                let pointer = Rect::new(
+                                                    10.0, 10.0, 
+                                                    12.0, SIZE * 1.5 - 10.0
+                                                )
+                                                .to_rounded_rect(SIZE * 0.1);
+                                                ctx.fill(pointer, &THEMATICS[data.board.gui_theme_no][4]);
+                                            }
+                                        },
+                                        _ => ()
+                                    };
+                                }
+                            }
+                        )
+                    )
+                    .on_click(
+                        {
+                            let row = i; let col = j;
+                            move |_ctx: &mut EventCtx, data: &mut AppState, _env: &Env| {
+                                data.board.select(row, col);
+                            }
+                        }
+                    )
+            );
+            guess_ui_r.add_spacer(SPACE);
+        }
+
+        guess_ui.add_spacer(SPACE);
+        guess_ui.add_child(
+            ZStack::new(guess_ui_r)
+                .with_centered_child(
+                    Label::new(
+                        {
+                            let row = i;
+                            move |data: &AppState, _env: &Env| {
+                                match data.board.gui_current_loc[0] == row {
+                                    true    => data.notice.clone(),
+                                    false   => String::new()
+                                }
+                            }
+                        }
+                    )
+                    .with_font(mont.clone())
+                    .with_text_color(Color::WHITE)
+                    .padding(SPACE / 2.0)
+                    .background(
+                        Painter::new(
+                            {
+                                let row = i;
+                                move |ctx: &mut PaintCtx, data: &AppState, _env: &Env| {
+                                    if data.board.gui_current_loc[0] == row && !data.notice.is_empty() {
+                                        let rnd = ctx.size().to_rounded_rect(SIZE / 4.0);
+                                        ctx.fill(rnd, &THEMATICS[data.board.gui_theme_no][1]);
+                                    }
+                                }
+                            }
+                        )
+                    )
+            )
+        );
+    }
+
+    let keyboard_layout = KEYBOARD_LAYOUT;
+
+    for i in 0..keyboard_layout.len() {
+        let mut keyboard_r: Flex<AppState> = Flex::row();
+
+        for j in 0..keyboard_layout[i].len() {
+            if !keyboard_layout[i][j].is_alphabetic() {
+                break;
+            }
+
+            keyboard_r.add_child(
+                Flex::column()
+                    .with_flex_spacer(SPACE / 4.0)
+                    .with_child(
+                        Flex::row()
+                            .with_flex_spacer(SPACE / 8.0)
+                            .with_child(
+                                Label::new(
+                                    {
+                                        let row = i; let col = j;
+                                        move |_data: &AppState, _env: &Env| {
+                                            String::from(keyboard_layout[row][col])
+                                        }
+                                    }
+                                )
+                                .with_font(mont.clone())
+                            )
+                            .with_flex_spacer(SPACE / 8.0)
+                            .fix_width(SIZE)
+                    )
+                    .with_flex_spacer(SPACE / 4.0)
+                    .fix_height(SIZE * 1.5)
+                    .background(
+                        {
+                            let row = i; let col = j;
+                            Painter::new(
+                                move |ctx: &mut PaintCtx, data: &AppState, _env: &Env| {
+                                    let rnd = ctx.size().to_rounded_rect(SIZE / 4.0);
+                                    let c = data.board.gui_letter_hint[(keyboard_layout[row][col] as usize) - 65];
+                                    ctx.fill(rnd, &tile_colour(&data.board, c));
+                                }
+                            )
+                        }
+                    )
+                    .on_click(
+                        {
+                            let row = i; let col = j;
+                            move |_ctx: &mut EventCtx, data: &mut AppState, _env: &Env| {
+                                type_letter(data, keyboard_layout[row][col]);
+                            }
+                        }
+                    )
+            );
+
+            keyboard_r.add_spacer(SPACE);
+        }
+
+        keyboard.add_spacer(SPACE);
+        keyboard.add_child(keyboard_r);
+    }
+
+    let theme_len = THEMATICS.len();
+    for i in 0..theme_len {
+        theme_switch.add_child(
+            Painter::new(
+                {
+                    let c = i;
+                    move |ctx: &mut PaintCtx, _data: &AppState, _env: &Env| {
+                        let rnd = ctx.size().to_rounded_rect(SIZE / 2.0);
+                        ctx.fill(rnd, &THEMATICS[c][3]);
+                    }
+                }
+            )
+            .fix_height(SIZE)
+            .fix_width(SIZE)
+            .on_click(
+                {
+                    let c = i;
+                    move |_ctx: &mut EventCtx, data: &mut AppState, _env: &Env| {
+                        data.board.gui_theme_no = c;
+                        save_game(data);
+                    }
+                }
+            )
+        );
+        theme_switch.add_spacer(SPACE);
+    }
+
+    // Hard mode toggle, locked once the first guess is in
+    theme_switch.add_child(
+        Flex::column()
+            .with_flex_spacer(SPACE / 8.0)
+            .with_child(
+                Label::new(String::from("H"))
+                    .with_font(mont.clone())
+            )
+            .with_flex_spacer(SPACE / 8.0)
+            .fix_height(SIZE)
+            .fix_width(SIZE)
+            .background(
+                Painter::new(
+                    move |ctx: &mut PaintCtx, data: &AppState, _env: &Env| {
+                        let rnd = ctx.size().to_rounded_rect(SIZE / 4.0);
+                        let c = match data.board.hard_mode {
+                            true    => THEMATICS[data.board.gui_theme_no][3],
+                            false   => THEMATICS[data.board.gui_theme_no][0]
+                        };
+                        ctx.fill(rnd, &c);
+
+                        if data.board.status != BoardStatus::NotStarted {
+                            ctx.stroke(rnd, &THEMATICS[data.board.gui_theme_no][4], 2.0);
+                        }
+                    }
+                )
+            )
+            .on_click(
+                move |_ctx: &mut EventCtx, data: &mut AppState, _env: &Env| {
+                    let on = !data.board.hard_mode;
+                    match data.board.set_hard_mode(on) {
+                        Ok(_)   => {
+                            data.notice = String::new();
+                            save_game(data);
+                        },
+                        Err(_)  => data.notice = String::from("Hard mode is locked")
+                    };
+                }
+            )
+    );
+    theme_switch.add_spacer(SPACE);
+
+    // Statistics
+    theme_switch.add_child(
+        Flex::column()
+            .with_flex_spacer(SPACE / 8.0)
+            .with_child(
+                Label::new(String::from("S"))
+                    .with_font(mont.clone())
+            )
+            .with_flex_spacer(SPACE / 8.0)
+            .fix_height(SIZE)
+            .fix_width(SIZE)
+            .background(
+                Painter::new(
+                    move |ctx: &mut PaintCtx, data: &AppState, _env: &Env| {
+                        let rnd = ctx.size().to_rounded_rect(SIZE / 4.0);
+                        ctx.fill(rnd, &THEMATICS[data.board.gui_theme_no][0]);
+                    }
+                )
+            )
+            .on_click(
+                move |_ctx: &mut EventCtx, data: &mut AppState, _env: &Env| {
+                    data.show_stats = !data.show_stats;
+                }
+            )
+    );
+    theme_switch.add_spacer(SPACE);
+
+    // High contrast (colour-blind) toggle, shown in the colours it switches to
+    theme_switch.add_child(
+        Flex::column()
+            .with_flex_spacer(SPACE / 8.0)
+            .with_child(
+                Label::new(String::from("C"))
+                    .with_font(mont.clone())
+            )
+            .with_flex_spacer(SPACE / 8.0)
+            .fix_height(SIZE)
+            .fix_width(SIZE)
+            .background(
+                Painter::new(
+                    move |ctx: &mut PaintCtx, data: &AppState, _env: &Env| {
+                        let rnd = ctx.size().to_rounded_rect(SIZE / 4.0);
+                        let c = match data.board.high_contrast {
+                            true    => rgb(CONTRAST_CORRECT),
+                            false   => THEMATICS[data.board.gui_theme_no][0]
+                        };
+                        ctx.fill(rnd, &c);
+                    }
+                )
+            )
+            .on_click(
+                move |_ctx: &mut EventCtx, data: &mut AppState, _env: &Env| {
+                    data.board.high_contrast = !data.board.high_contrast;
+                    save_game(data);
+                }
+            )
+    );
+    theme_switch.add_spacer(SPACE);
+
+    // Challenge: the word typed into the current row becomes a code to send
+    theme_switch.add_child(
+        Flex::column()
+            .with_flex_spacer(SPACE / 8.0)
+            .with_child(
+                Label::new(String::from("@"))
+                    .with_font(mont.clone())
+            )
+            .with_flex_spacer(SPACE / 8.0)
+            .fix_height(SIZE)
+            .fix_width(SIZE)
+            .background(
+                Painter::new(
+                    move |ctx: &mut PaintCtx, data: &AppState, _env: &Env| {
+                        let rnd = ctx.size().to_rounded_rect(SIZE / 4.0);
+                        ctx.fill(rnd, &THEMATICS[data.board.gui_theme_no][0]);
+                    }
+                )
+            )
+            .on_click(
+                move |_ctx: &mut EventCtx, data: &mut AppState, _env: &Env| {
+                    challenge(data);
+                }
+            )
+    );
+    theme_switch.add_spacer(SPACE);
+
+    keyboard.add_spacer(SPACE * 1.5);
+    keyboard.add_child(
+        Flex::row()
+            .with_flex_spacer(SPACE / 4.0)
+            .with_child(
+                Flex::column()
+                    .with_flex_spacer(SPACE / 8.0)
+                    .with_child(
+                        Label::new(String::from("SUBMIT"))
+                            .with_font(mont.clone())
+                    )
+                    .with_flex_spacer(SPACE / 8.0)
+                    .fix_height(SIZE * 1.5)
+                    .fix_width(SIZE * 4.0)
+                    .background(
+                        Painter::new(
+                            move |ctx: &mut PaintCtx, data: &AppState, _env: &Env| {
+                                let rnd = ctx.size().to_rounded_rect(SIZE / 4.0);
+                                ctx.fill(rnd, &THEMATICS[data.board.gui_theme_no][0]);
+                            }
+                        )
+                    )
+                    .on_click(
+                        move |_ctx: &mut EventCtx, data: &mut AppState, _env: &Env| {
+                            submit(data);
+                        }
+                    )
+            )
+            .with_spacer(SPACE)
+            .with_child(
+                Flex::column()
+                    .with_flex_spacer(SPACE / 8.0)
+                    .with_child(
+                        Label::new(String::from("<<"))
+                            .with_font(mont.clone())
+                    )
+                    .with_flex_spacer(SPACE / 8.0)
+                    .fix_height(SIZE * 1.5)
+                    .fix_width(SIZE * 1.5)
+                    .background(
+                        Painter::new(
+                            move |ctx: &mut PaintCtx, data: &AppState, _env: &Env| {
+                                let rnd = ctx.size().to_rounded_rect(SIZE / 4.0);
+                                ctx.fill(rnd, &THEMATICS[data.board.gui_theme_no][0]);
+                            }
+                        )
+                    )
+                    .on_click(
+                        move |_ctx: &mut EventCtx, data: &mut AppState, _env: &Env| {
+                            delete_letter(data);
+                        }
+                    )
+            )
+            .with_spacer(SPACE)
+            .with_child(
+                Flex::column()
+                    .with_flex_spacer(SPACE / 8.0)
+                    .with_child(
+                        Label::new(String::from("HINT"))
+                            .with_font(mont.clone())
+                    )
+                    .with_flex_spacer(SPACE / 8.0)
+                    .fix_height(SIZE * 1.5)
+                    .fix_width(SIZE * 2.5)
+                    .background(
+                        Painter::new(
+                            move |ctx: &mut PaintCtx, data: &AppState, _env: &Env| {
+                                let rnd = ctx.size().to_rounded_rect(SIZE / 4.0);
+                                ctx.fill(rnd, &THEMATICS[data.board.gui_theme_no][0]);
+                            }
+                        )
+                    )
+                    .on_click(
+                        move |_ctx: &mut EventCtx, data: &mut AppState, _env: &Env| {
+                            hint(data);
+                        }
+                    )
+            )
+            .with_flex_spacer(SPACE / 4.0)
+            .fix_width(SIZE * 4.0 + SPACE + SIZE * 1.5 + SPACE + SIZE * 2.5)
+    );
+    keyboard.add_spacer(SPACE * 1.5);
+    keyboard.add_child(
+        Flex::row()
+            .with_flex_spacer(SPACE / 4.0)
+            .with_child(
+                Flex::column()
+                    .with_flex_spacer(SPACE / 8.0)
+                    .with_child(
+                        Label::new(
+                            move |data: &AppState, _env: &Env| {
+                                if let Some(race) = &data.race {
+                                    return match data.board.is_playing() {
+                                        true    => format!("{}   {}", race.summary(), data.clock),
+                                        false   => format!("{} The word was {}.", race.summary(), data.board.answer)
+                                    };
+                                }
+
+                                match (&data.speedrun, &data.board.status) {
+                                    (Some(run), BoardStatus::Win(_))    => format!("Run complete in {}! New run?", Stopwatch(run.time().unwrap_or_default())),
+                                    (Some(run), BoardStatus::Lose)      => format!("Run over at word {}! The word was {}. New run?", run.word(), data.board.answer),
+                                    (None, BoardStatus::Win(n))         => format!("You have won in {} tries! New game?", n + 1),
+                                    (None, BoardStatus::Lose)           => format!("You have lost! The word was {}. New game?", data.board.answer),
+                                    _                                   => data.clock.clone()
+                                }
+                            }
+                        )
+                        .with_font(mont.clone())
+                        .controller(Ticker { timer: TimerToken::INVALID })
+                    )
+                    .with_flex_spacer(SPACE / 8.0)
+                    .fix_height(SIZE * 1.5)
+            )
+            .with_flex_spacer(SPACE / 4.0)
+            .fix_width(SIZE * 10.0 + SPACE * 9.0)
+            .background(
+                Painter::new(
+                    move |ctx: &mut PaintCtx, data: &AppState, _env: &Env| {
+                        let rnd = ctx.size().to_rounded_rect(SIZE / 4.0);
+                        let c = match data.board.status {
+                            BoardStatus::Win(_) => &THEMATICS[data.board.gui_theme_no][3],
+                            BoardStatus::Lose   => &THEMATICS[data.board.gui_theme_no][1],
+                            _                   => &THEMATICS[data.board.gui_theme_no][0]
+                        };
+
+                        ctx.fill(rnd, c);
+                    }
+                )
+            )
+            .on_click(
+                move |_ctx: &mut EventCtx, data: &mut AppState, _env: &Env| {
+                    if !data.board.is_playing() {
+                        new_game(data);
+                    }
+                }
+            )
+    );
+
+    let mut game_ui = Flex::row()
+        .with_flex_spacer(SPACE)
+        .with_child(
+            guess_ui
+                .with_flex_spacer(SPACE)
+                .fix_height(grid_height(rules))
+        )
+        .with_spacer(SPACE * 4.0)
+        .with_child(
+            Flex::column()
+                .fix_height(SIZE * 7.5)
+                .fix_width(1.0)
+                .background(Color::GRAY)
+        )
+        .with_spacer(SPACE * 5.0)
+        .with_child(
+            keyboard
+                .with_flex_spacer(SPACE)
+                .fix_height(
+                    (SIZE * 1.5 * 3.0 + SPACE * 2.0) + (SPACE * 1.5 + SIZE * 1.5) + (SPACE * 1.5 + SIZE * 1.5) + SPACE * 2.0
+                )
+        )
+        .with_spacer(SPACE * 4.0)
+        .with_child(
+            Flex::column()
+                .fix_height(SIZE * 7.5)
+                .fix_width(1.0)
+                .background(Color::GRAY)
+        )
+        .with_spacer(SPACE * 5.0)
+        .with_child(
+            theme_switch
+                .with_flex_spacer(SPACE)
+                .fix_height(SIZE * (theme_len as f64 + 4.0) + SPACE * (theme_len as f64 + 4.0) + SPACE * 2.0)
+        );
+    if racing {
+        game_ui.add_spacer(SPACE * 4.0);
+        game_ui.add_child(race_ui(rules));
+    }
+
+    let game_ui = game_ui
+        .with_flex_spacer(SPACE)
+        .fix_width(
+            SPACE + grid_width(rules) + (SPACE * 9.0 + 1.0) + (SIZE * 9.0 + SPACE * 9.0) + (SPACE * 9.0 + 1.0) + (SIZE) + SPACE + race_width(racing)
+        )
+        .background(
+            Painter::new(
+                move |ctx: &mut PaintCtx, data: &AppState, _env: &Env| {
+                    let bg = ctx.size().to_rect();
+                    ctx.fill(bg, &THEMATICS[data.board.gui_theme_no][5]);
+                }
+            )
+        );
+
+    ZStack::new(game_ui)
+        .with_centered_child(
+            Either::new(
+                |data: &AppState, _env: &Env| data.show_stats,
+                stats_ui(rules),
+                SizedBox::empty()
+            )
+        )
+        .controller(KeyboardInput)
+        .controller(RaceUpdates)
+}
+
+// Race panel
+//  Each opponent's name over a small grid of the rows they've played,
+//  in colour only (the letters never leave their machine).
+const RACE_WIDTH: f64 = SIZE * 5.0;
+const MINI: f64 = SIZE / 4.0;
+
+fn race_ui(rules: Rules) -> impl Widget<AppState> {
+    let step = MINI + 2.0;
+    let block = (rules.length as f64 * step + SPACE * 2.0, rules.attempts as f64 * step + SPACE * 4.0);
+
+    Painter::new(
+        move |ctx: &mut PaintCtx, data: &AppState, env: &Env| {
+            let race = match &data.race {
+                Some(r) => r,
+                None    => return
+            };
+            let across = ((ctx.size().width / block.0) as usize).max(1);
+            let text_colour = env.get(druid::theme::TEXT_COLOR);
+
+            for (n, racer) in race.opponents().enumerate() {
+                let (x, y) = ((n % across) as f64 * block.0, (n / across) as f64 * block.1);
+
+                let name = match racer.left {
+                    true    => format!("{} (left)", racer.name),
+                    false   => racer.name.clone()
+                };
+                let layout = ctx.text().new_text_layout(name)
+                    .font(FontFamily::new_unchecked("Montserrat"), 14.0)
+                    .text_color(text_colour)
+                    .build();
+                if let Ok(layout) = layout {
+                    ctx.draw_text(&layout, (x, y));
+                }
+
+                for row in 0..rules.attempts {
+                    for col in 0..rules.length {
+                        let state = racer.rows.get(row).map_or(TileState::Pending, |r| r.state(col));
+                        let tile = Rect::from_origin_size((x + col as f64 * step, y + SPACE * 2.0 + row as f64 * step), (MINI, MINI))
+                            .to_rounded_rect(2.0);
+                        ctx.fill(tile, &tile_colour(&data.board, state));
+                    }
+                }
+            }
+        }
+    )
+    .fix_width(RACE_WIDTH)
+    .fix_height(grid_height(rules))
+}
+
+// Statistics panel
+//  Totals on top, then one bar per guess count; the bar for the game
+//  just won is drawn in the "right" colour. Share and Close underneath.
+fn stats_ui(rules: Rules) -> impl Widget<AppState> {
+    let mont: FontDescriptor = FontDescriptor::new(
+            FontFamily::new_unchecked("Montserrat")
+        )
+        .with_weight(FontWeight::SEMI_BOLD)
+        .with_size(20.0);
+
+    let small = mont.clone().with_size(14.0);
+
+    let figure = |value: fn(&Stats) -> u32, caption: &str| {
+        Flex::column()
+            .with_child(
+                Label::new(move |data: &AppState, _env: &Env| value(&data.stats).to_string())
+                    .with_font(mont.clone())
+            )
+            .with_child(
+                Label::new(String::from(caption))
+                    .with_font(small.clone())
+            )
+            .fix_width(SIZE * 2.0)
+    };
+
+    let mut panel: Flex<AppState> = Flex::column()
+        .with_child(
+            Label::new(String::from("STATISTICS"))
+                .with_font(mont.clone())
+        )
+        .with_spacer(SPACE)
+        .with_child(
+            Flex::row()
+                .with_child(figure(|s| s.played, "Played"))
+                .with_child(figure(Stats::win_percent, "Win %"))
+                .with_child(figure(|s| s.current_streak, "Streak"))
+                .with_child(figure(|s| s.max_streak, "Max streak"))
+        )
+        .with_spacer(SPACE / 2.0)
+        .with_child(
+            Label::new(
+                |data: &AppState, _env: &Env| {
+                    let best = match &data.speedrun {
+                        Some(run)   => data.stats.best_speedrun(run.answers.len()).map(|t| format!("Best {}-word run {}", run.answers.len(), Stopwatch(t))),
+                        None        => data.stats.best_time.map(|t| format!("Fastest win {}", Stopwatch(t)))
+                    };
+                    best.unwrap_or_default()
+                }
+            )
+            .with_font(small.clone())
+        )
+        .with_spacer(SPACE * 1.5)
+        .with_child(
+            Label::new(String::from("GUESS DISTRIBUTION"))
+                .with_font(mont.clone())
+        )
+        .with_spacer(SPACE);
+
+    for n in 1..=rules.attempts {
+        panel.add_child(
+            Flex::row()
+                .with_child(
+                    Label::new(n.to_string())
+                        .with_font(mont.clone())
+                        .fix_width(SIZE / 2.0)
+                )
+                .with_child(
+                    Painter::new(
+                        move |ctx: &mut PaintCtx, data: &AppState, _env: &Env| {
+                            let most = (1..=rules.attempts).map(|k| data.stats.won_in(k)).max().unwrap_or(0).max(1);
+                            let size = ctx.size();
+                            let bar = Rect::new(
+                                0.0, 0.0,
+                                (size.width * data.stats.won_in(n) as f64 / most as f64).max(SIZE / 4.0), size.height
+                            )
+                            .to_rounded_rect(SIZE / 8.0);
+
+                            let c = match data.board.status {
+                                BoardStatus::Win(won) if won + 1 == n   => tile_colour(&data.board, TileState::Correct),
+                                _                                       => tile_colour(&data.board, TileState::Absent)
+                            };
+                            ctx.fill(bar, &c);
+                        }
+                    )
+                    .fix_width(SIZE * 6.0)
+                    .fix_height(SIZE / 2.0)
+                )
+                .with_spacer(SPACE)
+                .with_child(
+                    Label::new(move |data: &AppState, _env: &Env| data.stats.won_in(n).to_string())
+                        .with_font(small.clone())
+                        .fix_width(SIZE)
+                )
+        );
+        panel.add_spacer(SPACE / 2.0);
+    }
+
+    let button = |text: &str, action: fn(&mut AppState)| {
+        Flex::column()
+            .with_flex_spacer(SPACE / 8.0)
+            .with_child(
+                Label::new(String::from(text))
+                    .with_font(mont.clone())
+            )
+            .with_flex_spacer(SPACE / 8.0)
+            .fix_height(SIZE)
+            .fix_width(SIZE * 2.5)
+            .background(
+                Painter::new(
+                    move |ctx: &mut PaintCtx, data: &AppState, _env: &Env| {
+                        let rnd = ctx.size().to_rounded_rect(SIZE / 4.0);
+                        ctx.fill(rnd, &THEMATICS[data.board.gui_theme_no][4]);
+                    }
+                )
+            )
+            .on_click(
+                move |_ctx: &mut EventCtx, data: &mut AppState, _env: &Env| {
+                    action(data);
+                }
+            )
+    };
+
+    panel
+        .with_spacer(SPACE)
+        .with_child(
+            Flex::row()
+                .with_child(button("SHARE", share))
+                .with_spacer(SPACE)
+                .with_child(button("CLOSE", |data| data.show_stats = false))
+        )
+        .with_spacer(SPACE / 2.0)
+        .with_child(
+            Label::new(|data: &AppState, _env: &Env| data.notice.clone())
+                .with_font(small)
+        )
+        .padding(SPACE * 2.0)
+        .background(
+            Painter::new(
+                move |ctx: &mut PaintCtx, data: &AppState, _env: &Env| {
+                    let rnd = ctx.size().to_rounded_rect(SIZE / 4.0);
+                    ctx.fill(rnd, &THEMATICS[data.board.gui_theme_no][0]);
+                    ctx.stroke(rnd, &THEMATICS[data.board.gui_theme_no][4], 2.0);
+                }
+            )
+        )
+}
\ No newline at end of file
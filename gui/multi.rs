@@ -0,0 +1,405 @@
+// Multi-board window
+//  Dordle, Quordle and Octordle on the same engine. Boards sit in rows of
+//  up to four with smaller tiles, and every keyboard key is split into one
+//  patch per board, laid out the same way as the boards.
+use wordle_nova::{Board, BoardStatus, LaunchOptions, MultiBoard, Rules, TileState, KEYBOARD_LAYOUT};
+
+use druid::{
+    Data, Lens, Env, EventCtx, PaintCtx, RenderContext,
+    AppLauncher, Application, WindowDesc, PlatformError,
+    Widget, WidgetExt, Rect, FontDescriptor, FontFamily, FontWeight
+};
+
+use druid::widget::{Label, Flex, Painter};
+
+use super::{tile_colour, Input, KeyboardInput, SIZE, SPACE, THEMATICS};
+
+// MultiState
+#[derive(Clone, Data, Lens)]
+pub struct MultiState {
+    #[data(eq)] game: MultiBoard,
+    notice: String
+}
+
+// Actions, shared by the buttons and the physical keyboard
+impl Input for MultiState {
+    fn type_letter(&mut self, c: char) {
+        self.game.push_letter(c);
+        self.notice.clear();
+    }
+
+    fn delete_letter(&mut self) {
+        self.game.pop_letter();
+        self.notice.clear();
+    }
+
+    // Enter on a finished game starts the next one
+    fn submit(&mut self) {
+        if !self.game.is_playing() {
+            return self.new_game();
+        }
+
+        self.notice = match self.game.submit_row() {
+            Ok(_)   => String::new(),
+            Err(e)  => e.to_string()
+        };
+    }
+
+    fn move_cursor(&mut self, right: bool) {
+        if let Some([row, col]) = self.game.current_loc() {
+            match right {
+                true    => self.game.select(row, col + 1),
+                false   => self.game.select(row, col.saturating_sub(1))
+            };
+        }
+    }
+
+    fn dismiss(&mut self) {
+        self.notice.clear();
+    }
+} impl MultiState {
+    fn new_game(&mut self) {
+        self.notice = match self.game.reset() {
+            Ok(_)   => String::new(),
+            Err(e)  => e
+        };
+    }
+
+    fn share(&mut self) {
+        self.notice = match self.game.share() {
+            Ok(text)    => {
+                Application::global().clipboard().put_string(text);
+                String::from("Copied to clipboard")
+            },
+            Err(_)      => String::from("Finish the game to share it")
+        };
+    }
+}
+
+// Launch
+//  saved is the single-board game on disk, only its settings are used.
+pub fn run(options: LaunchOptions, saved: Option<Board>) -> Result<(), PlatformError> {
+    let mut game = match options.multi_board() {
+        Ok(g)   => g,
+        Err(e)  => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+    if let Some(seed) = game.seed {
+        println!("Game seed {} (replay with --seed {})", seed, seed);
+    }
+
+    if let Some(saved) = saved {
+        for board in game.boards.iter_mut() {
+            board.hard_mode = saved.hard_mode;
+            board.high_contrast = saved.high_contrast;
+            board.gui_theme_no = saved.gui_theme_no;
+        }
+    }
+
+    let layout = Layout::new(game.boards.len(), game.rules());
+    let window = WindowDesc::new(multi_ui(layout))
+        .title(
+            // The seed changes with each new game, so any of them can be replayed
+            move |data: &MultiState, _env: &Env| match data.game.seed {
+                Some(seed)  => format!("Wordle_Beta - {} - Seed {}", MultiBoard::name(layout.count), seed),
+                None        => format!("Wordle_Beta - {}", MultiBoard::name(layout.count))
+            }
+        )
+        .window_size(layout.window_size());
+
+    AppLauncher::with_window(window)
+        .log_to_console()
+        .launch(MultiState { game, notice: String::new() })
+}
+
+// Layout
+//  Tile size shrinks as boards are added so the window stays on screen.
+#[derive(Clone, Copy)]
+struct Layout {
+    count: usize,
+    rules: Rules,
+    columns: usize,
+    rows: usize,
+    scale: f64
+} impl Layout {
+    fn new(count: usize, rules: Rules) -> Layout {
+        Layout {
+            count,
+            rules,
+            columns: count.min(4),
+            rows: count.div_ceil(4),
+            scale: match count {
+                0..=2   => 0.8,
+                3..=4   => 0.6,
+                _       => 0.5
+            }
+        }
+    }
+
+    fn tile(&self) -> (f64, f64) {
+        (SIZE * self.scale, SIZE * self.scale * 1.2)
+    }
+
+    fn gap(&self) -> f64 {
+        SPACE * self.scale / 2.0
+    }
+
+    fn board_size(&self) -> (f64, f64) {
+        let (w, h) = self.tile();
+        (
+            w * self.rules.length as f64 + self.gap() * (self.rules.length as f64 - 1.0),
+            h * self.rules.attempts as f64 + self.gap() * (self.rules.attempts as f64 - 1.0)
+        )
+    }
+
+    fn window_size(&self) -> (f64, f64) {
+        let (w, h) = self.board_size();
+        let boards = (
+            w * self.columns as f64 + SPACE * 2.0 * (self.columns as f64 - 1.0),
+            h * self.rows as f64 + SPACE * 2.0 * (self.rows as f64 - 1.0)
+        );
+        let keyboard = (SIZE * 10.0 + SPACE * 9.0, (SIZE * 1.5 + SPACE) * 5.0 + SPACE * 4.0);
+
+        (SPACE * 2.0 + boards.0 + SPACE * 10.0 + keyboard.0 + SPACE * 2.0, boards.1.max(keyboard.1) + 200.0)
+    }
+}
+
+fn multi_ui(layout: Layout) -> impl Widget<MultiState> {
+    let mont: FontDescriptor = FontDescriptor::new(
+            FontFamily::new_unchecked("Montserrat")
+        )
+        .with_weight(FontWeight::SEMI_BOLD)
+        .with_size(20.0);
+
+    let small = mont.clone().with_size(20.0 * layout.scale.max(0.6));
+
+    // Boards
+    let (tile_w, tile_h) = layout.tile();
+    let mut boards: Flex<MultiState> = Flex::column();
+
+    for r in 0..layout.rows {
+        let mut boards_r: Flex<MultiState> = Flex::row();
+
+        for b in (r * layout.columns)..((r + 1) * layout.columns).min(layout.count) {
+            let mut grid: Flex<MultiState> = Flex::column();
+
+            for i in 0..layout.rules.attempts {
+                let mut grid_r: Flex<MultiState> = Flex::row();
+
+                for j in 0..layout.rules.length {
+                    grid_r.add_child(
+                        Label::new(
+                            {
+                                let row = i; let col = j;
+                                move |data: &MultiState, _env: &Env| {
+                                    String::from(data.game.boards[b].guesses[row][col])
+                                }
+                            }
+                        )
+                        .with_font(small.clone())
+                        .center()
+                        .fix_size(tile_w, tile_h)
+                        .background(
+                            Painter::new(
+                                {
+                                    let row = i; let col = j;
+                                    move |ctx: &mut PaintCtx, data: &MultiState, _env: &Env| {
+                                        let board = &data.game.boards[b];
+                                        let rnd = ctx.size().to_rounded_rect(tile_w / 4.0);
+                                        ctx.fill(rnd, &tile_colour(board, board.correction[row].state(col)));
+
+                                        if board.is_playing() && board.gui_current_loc == [row, col] {
+                                            let pointer = Rect::new(
+                                                tile_w * 0.15, tile_h * 0.15,
+                                                tile_w * 0.15 + 2.0, tile_h * 0.85
+                                            )
+                                            .to_rounded_rect(1.0);
+                                            ctx.fill(pointer, &THEMATICS[board.gui_theme_no][4]);
+                                        }
+                                    }
+                                }
+                            )
+                        )
+                        .on_click(
+                            {
+                                let row = i; let col = j;
+                                move |_ctx: &mut EventCtx, data: &mut MultiState, _env: &Env| {
+                                    data.game.select(row, col);
+                                }
+                            }
+                        )
+                    );
+                    grid_r.add_spacer(layout.gap());
+                }
+
+                grid.add_child(grid_r);
+                grid.add_spacer(layout.gap());
+            }
+
+            // Solved boards get a border in the "right" colour
+            boards_r.add_child(
+                grid
+                    .padding(SPACE / 2.0)
+                    .background(
+                        Painter::new(
+                            move |ctx: &mut PaintCtx, data: &MultiState, _env: &Env| {
+                                let board = &data.game.boards[b];
+                                if let BoardStatus::Win(_) = board.status {
+                                    let rnd = ctx.size().to_rounded_rect(SPACE / 2.0);
+                                    ctx.stroke(rnd, &tile_colour(board, TileState::Correct), 2.0);
+                                }
+                            }
+                        )
+                    )
+            );
+            boards_r.add_spacer(SPACE);
+        }
+
+        boards.add_child(boards_r);
+        boards.add_spacer(SPACE);
+    }
+
+    // Keyboard, each key split into one patch per board
+    let mut keyboard: Flex<MultiState> = Flex::column();
+
+    for keyboard_row in KEYBOARD_LAYOUT.iter() {
+        let mut keyboard_r: Flex<MultiState> = Flex::row();
+
+        for &letter in keyboard_row.iter() {
+            if !letter.is_alphabetic() {
+                break;
+            }
+
+            keyboard_r.add_child(
+                Label::new(String::from(letter))
+                    .with_font(mont.clone())
+                    .center()
+                    .fix_size(SIZE, SIZE * 1.5)
+                    .background(
+                        Painter::new(
+                            move |ctx: &mut PaintCtx, data: &MultiState, _env: &Env| {
+                                let size = ctx.size();
+                                let (w, h) = (size.width / layout.columns as f64, size.height / layout.rows as f64);
+
+                                for (b, hint) in data.game.letter_hints(letter).into_iter().enumerate() {
+                                    let (x, y) = ((b % layout.columns) as f64 * w, (b / layout.columns) as f64 * h);
+                                    ctx.fill(Rect::new(x, y, x + w, y + h), &tile_colour(&data.game.boards[b], hint));
+                                }
+
+                                let rnd = size.to_rounded_rect(SIZE / 4.0);
+                                ctx.stroke(rnd, &THEMATICS[data.game.boards[0].gui_theme_no][5], 2.0);
+                            }
+                        )
+                    )
+                    .on_click(
+                        move |_ctx: &mut EventCtx, data: &mut MultiState, _env: &Env| {
+                            data.type_letter(letter);
+                        }
+                    )
+            );
+            keyboard_r.add_spacer(SPACE);
+        }
+
+        keyboard.add_child(keyboard_r);
+        keyboard.add_spacer(SPACE);
+    }
+
+    let button = |text: &str, width: f64, action: fn(&mut MultiState)| {
+        Label::new(String::from(text))
+            .with_font(mont.clone())
+            .center()
+            .fix_size(width, SIZE * 1.5)
+            .background(
+                Painter::new(
+                    move |ctx: &mut PaintCtx, data: &MultiState, _env: &Env| {
+                        let rnd = ctx.size().to_rounded_rect(SIZE / 4.0);
+                        ctx.fill(rnd, &THEMATICS[data.game.boards[0].gui_theme_no][0]);
+                    }
+                )
+            )
+            .on_click(
+                move |_ctx: &mut EventCtx, data: &mut MultiState, _env: &Env| {
+                    action(data);
+                }
+            )
+    };
+
+    keyboard.add_spacer(SPACE / 2.0);
+    keyboard.add_child(
+        Flex::row()
+            .with_child(button("SUBMIT", SIZE * 4.0, MultiState::submit))
+            .with_spacer(SPACE)
+            .with_child(button("<<", SIZE * 1.5, MultiState::delete_letter))
+            .with_spacer(SPACE)
+            .with_child(button("SHARE", SIZE * 2.5, MultiState::share))
+    );
+    keyboard.add_spacer(SPACE * 1.5);
+    keyboard.add_child(
+        Label::new(
+            move |data: &MultiState, _env: &Env| {
+                match (data.game.status(), data.notice.is_empty()) {
+                    (_, false)                  => data.notice.clone(),
+                    (BoardStatus::Win(n), _)    => format!("All {} solved in {} tries! New game?", layout.count, n + 1),
+                    (BoardStatus::Lose, _)      => {
+                        let missed: Vec<&str> = data.game.boards.iter()
+                            .filter(|b| b.status == BoardStatus::Lose)
+                            .map(|b| b.answer.as_str())
+                            .collect();
+                        format!("Out of guesses! Missed {}. New game?", missed.join(", "))
+                    },
+                    _                           => String::new()
+                }
+            }
+        )
+        .with_font(mont.clone())
+        .center()
+        .fix_size(SIZE * 10.0 + SPACE * 9.0, SIZE * 1.5)
+        .background(
+            Painter::new(
+                move |ctx: &mut PaintCtx, data: &MultiState, _env: &Env| {
+                    let rnd = ctx.size().to_rounded_rect(SIZE / 4.0);
+                    let c = match data.game.status() {
+                        BoardStatus::Win(_) => tile_colour(&data.game.boards[0], TileState::Correct),
+                        BoardStatus::Lose   => tile_colour(&data.game.boards[0], TileState::Absent),
+                        _                   => THEMATICS[data.game.boards[0].gui_theme_no][0]
+                    };
+
+                    ctx.fill(rnd, &c);
+                }
+            )
+        )
+        .on_click(
+            move |_ctx: &mut EventCtx, data: &mut MultiState, _env: &Env| {
+                if !data.game.is_playing() {
+                    data.new_game();
+                }
+            }
+        )
+    );
+
+    Flex::row()
+        .with_spacer(SPACE * 2.0)
+        .with_child(boards)
+        .with_spacer(SPACE * 4.0)
+        .with_child(
+            Flex::column()
+                .fix_height(SIZE * 7.5)
+                .fix_width(1.0)
+                .background(druid::Color::GRAY)
+        )
+        .with_spacer(SPACE * 5.0)
+        .with_child(keyboard)
+        .with_flex_spacer(SPACE)
+        .center()
+        .background(
+            Painter::new(
+                move |ctx: &mut PaintCtx, data: &MultiState, _env: &Env| {
+                    let bg = ctx.size().to_rect();
+                    ctx.fill(bg, &THEMATICS[data.game.boards[0].gui_theme_no][5]);
+                }
+            )
+        )
+        .controller(KeyboardInput)
+}
@@ -0,0 +1,54 @@
+// wordle_host
+//  Hosts a race for `wordle_tui --race <address>` (or the druid front-end).
+//  Everyone plays the same seeded word and the first to solve it wins.
+//  Players need the same word lists, since only the seed is sent out.
+//
+//  --bind <address>    where to listen (default 0.0.0.0:7878; 127.0.0.1:7878 for this machine only)
+//  --players <n>       start once this many have joined (default 2)
+//  --seed, --length and --attempts as for a game
+//
+//  exit:   0 once the race is over, 1 for bad options or a network error
+
+use std::process::ExitCode;
+
+use wordle_nova::{LaunchOptions, RaceHost, WordSource};
+
+fn main() -> ExitCode {
+    match host(std::env::args().skip(1).collect()) {
+        Ok(_)   => ExitCode::SUCCESS,
+        Err(e)  => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn host(args: Vec<String>) -> Result<(), String> {
+    let mut bind = String::from("0.0.0.0:7878");
+    let mut players = 2;
+    let mut game = Vec::new();
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let value = args.next().ok_or(format!("wordle_host: {} needs a value", arg))?;
+        match arg.as_str() {
+            "--bind"                                => bind = value,
+            "--players"                             => players = value.parse().map_err(|e| format!("wordle_host: Bad --players {}: {}", value, e))?,
+            "--seed" | "--length" | "--attempts"    => game.extend([arg, value]),
+            _                                       => return Err(format!("wordle_host: Unknown option {}", arg))
+        };
+    }
+
+    let options = LaunchOptions::from_args(game.into_iter())?;
+    let seed = options.seed.unwrap_or_else(|| WordSource::from_entropy().next_seed());
+
+    let host = RaceHost::bind(&bind, players, seed, options.rules)?;
+    println!("Waiting for {} players on {} (seed {})", players, host.local_addr()?, seed);
+
+    match host.run()? {
+        Some(name)  => println!("{} won the race", name),
+        None        => println!("Nobody solved it")
+    };
+
+    Ok(())
+}
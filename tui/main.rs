@@ -0,0 +1,275 @@
+// Imports
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Instant;
+
+use wordle_nova::{
+    tile_colour, Board, BoardStatus, Challenge, GameMode, GuessFeedback, LaunchOptions, PrErr, Race, RaceClient,
+    Rgb, Stats, Stopwatch, ToPlayer, KEYBOARD_LAYOUT, THEMES
+};
+
+// ANSI
+fn paint(text: &str, bg: Rgb) -> String {
+    // dark text on light tiles, light text on dark ones
+    let fg = match 299 * bg.0 as u32 + 587 * bg.1 as u32 + 114 * bg.2 as u32 > 150_000 {
+        true    => (18, 18, 19),
+        false   => (255, 255, 255)
+    };
+
+    format!("\x1b[1;38;2;{};{};{};48;2;{};{};{}m{}\x1b[0m", fg.0, fg.1, fg.2, bg.0, bg.1, bg.2, text)
+}
+
+fn subscript(n: Option<u8>) -> char {
+    match n {
+        Some(n) if n > 1    => char::from_u32(0x2080 + n as u32).unwrap_or(' '),
+        _                   => ' '
+    }
+}
+
+// Render
+fn render(board: &Board, race: Option<&Race>, theme: usize, notice: &str) -> String {
+    let mut out = String::from("\x1b[2J\x1b[H");
+
+    out += &match (board.mode, board.provider.remaining()) {
+        _ if race.is_some()         => String::from("  Wordle_Beta - Race"),
+        (GameMode::Daily(n), _)     => format!("  Wordle_Beta - Daily #{}", n),
+        (_, Some(n))                => format!("  Wordle_Beta - Absurdle ({} words left)", n),
+        (GameMode::FreePlay, None)  => match board.seed {
+            Some(seed)  => format!("  Wordle_Beta - Seed {}", seed), // so any game in a session can be replayed
+            None        => String::from("  Wordle_Beta")
+        }
+    };
+    if board.hard_mode {
+        out += " (hard)";
+    }
+
+    // Only as of the last redraw, the terminal isn't refreshed while waiting
+    let now = Instant::now();
+    out += &match board.clock.remaining(now) {
+        Some(left)                          => format!("  {} left", Stopwatch(left)),
+        None if board.clock.is_started()    => format!("  {}", Stopwatch(board.clock.elapsed(now))),
+        None                                => String::new()
+    };
+    out += "\n\n";
+
+    // Guess grid
+    for (row, guess) in board.guesses.iter().enumerate() {
+        out += "  ";
+        for (col, &c) in guess.iter().enumerate() {
+            let tile = board.correction[row].tile(col);
+            out += &paint(&format!(" {}{} ", c, subscript(tile.answer_count)), tile_colour(theme, board.high_contrast, tile.state));
+            out += " ";
+        }
+        out += "\n\n";
+    }
+
+    // Keyboard, coloured by the best hint seen for each letter
+    for (i, keys) in KEYBOARD_LAYOUT.iter().enumerate() {
+        out += &" ".repeat(2 + i * 2);
+        for &c in keys.iter().take_while(|c| c.is_ascii_alphabetic()) {
+            let hint = board.gui_letter_hint[(c as usize) - 65];
+            out += &paint(&format!(" {} ", c), tile_colour(theme, board.high_contrast, hint));
+            out += " ";
+        }
+        out += "\n";
+    }
+    out += "\n";
+
+    // Opponents, one line each with the colours of every row they've played
+    if let Some(race) = race {
+        out += &format!("  {}\n", race.summary());
+        for racer in race.opponents() {
+            out += &format!("  {:<12}", racer.name);
+            for row in &racer.rows {
+                for tile in row.iter() {
+                    out += &paint("  ", tile_colour(theme, board.high_contrast, tile.state));
+                }
+                out += " ";
+            }
+            if racer.left {
+                out += "(left)";
+            }
+            out += "\n";
+        }
+        out += "\n";
+    }
+
+    // Banner
+    out += &match board.status {
+        BoardStatus::Win(n) => paint(&format!(" You have won in {} tries! ", n + 1), THEMES[theme][3]),
+        BoardStatus::Lose   => paint(&format!(" You have lost! The word was {}. ", board.answer), THEMES[theme][1]),
+        _                   => String::new()
+    };
+    out += "\n";
+
+    if !notice.is_empty() {
+        out += &format!("  {}\n", notice);
+    }
+
+    out += match (board.is_playing(), race.is_some()) {
+        (true, false)   => "  Guess (:hint, :hard, :contrast, :stats, :challenge <word>, :theme <n>, :new, :quit) > ",
+        (true, true)    => "  Guess (:contrast, :stats, :theme <n>, :quit) > ",
+        (false, false)  => "  Enter for a new game, :share, :quit to leave > ",
+        (false, true)   => "  :share, :quit to leave > "
+    };
+
+    out
+}
+
+fn summary(stats: &Stats) -> String {
+    let bars: Vec<String> = (1..=stats.distribution.len()).map(|n| format!("{}:{}", n, stats.won_in(n))).collect();
+    format!(
+        "Played {}, won {}%, streak {} (max {}), guesses {}",
+        stats.played, stats.win_percent(), stats.current_streak, stats.max_streak, bars.join(" ")
+    )
+}
+
+// Counts a finished game, showing the stats or why they couldn't be saved
+fn record(stats: &mut Stats, stats_path: Option<&PathBuf>, board: &Board) -> String {
+    if !stats.record(board) {
+        return String::new();
+    }
+
+    match stats_path.map(|path| stats.save(path)) {
+        Some(Err(e))    => e,
+        _               => summary(stats)
+    }
+}
+
+// Passes a row on to the other racers while the race is still going
+fn send_row(race: &Option<(RaceClient, Race)>, feedback: &GuessFeedback) -> PrErr<()> {
+    match race {
+        Some((client, race)) if !race.over  => client.send_row(feedback),
+        _                                   => Ok(())
+    }
+}
+
+// Input
+//  Lines typed and messages from a race host arrive on the same channel,
+//  so the screen is redrawn as soon as an opponent plays a row.
+enum Input {
+    Line(String),
+    Closed, // nothing more to read from stdin
+    Race(PrErr<ToPlayer>)
+}
+
+// Main
+fn main() {
+    let (inputs, inbox) = mpsc::channel();
+
+    let options = LaunchOptions::from_args(std::env::args().skip(1));
+    let started = options.and_then(|options| match options.race.is_some() {
+        true    => {
+            let inputs = inputs.clone();
+            options.race(move |message| { let _ = inputs.send(Input::Race(message)); })
+                .map(|(client, race, board)| (board, Some((client, race))))
+        },
+        false   => options.board().map(|board| (board, None))
+    });
+    let (mut board, mut race) = match started {
+        Ok(s)   => s,
+        Err(e)  => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+
+    // A stats file that can't be read is left alone rather than overwritten
+    let (mut stats, stats_path) = match Stats::path().and_then(|path| Stats::load(&path).map(|stats| (stats, path))) {
+        Ok((stats, path))   => (stats, Some(path)),
+        Err(e)              => {
+            eprintln!("Stats won't be saved: {}", e);
+            (Stats::default(), None)
+        }
+    };
+
+    let mut theme = 2;
+    let mut notice = match (board.seed, &race) {
+        (Some(seed), None)  => format!("Game seed {} (replay with --seed {})", seed, seed),
+        _                   => String::new()
+    };
+
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            match line {
+                Ok(l)   => if inputs.send(Input::Line(l)).is_err() { return },
+                Err(_)  => break
+            };
+        }
+        let _ = inputs.send(Input::Closed);
+    });
+
+    loop {
+        print!("{}", render(&board, race.as_ref().map(|(_, r)| r), theme, &notice));
+        if io::stdout().flush().is_err() {
+            return;
+        }
+
+        let line = match inbox.recv() {
+            Ok(Input::Line(l))          => l,
+            Ok(Input::Race(message))    => {
+                if let Some((_, race)) = &mut race {
+                    match message {
+                        Ok(m)               => race.apply(&m),
+                        Err(_) if race.over => (), // the host closes up once the race is over
+                        Err(e)              => notice = e
+                    };
+                }
+                continue;
+            },
+            _                           => break
+        };
+        let line = line.trim();
+        let in_race = race.is_some();
+        let waiting = race.as_ref().is_some_and(|(_, r)| !r.started);
+
+        // A blitz game can run out while waiting for the line
+        if board.check_time(Instant::now()) {
+            notice = record(&mut stats, stats_path.as_ref(), &board);
+            continue;
+        }
+
+        notice = match line.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            [":quit"] | [":q"]  => break,
+            [":new"] if in_race => String::from("A race is a single word, :quit to leave"),
+            [":hint"] if in_race => String::from("No hints in a race"),
+            [] if in_race       => String::new(),
+            [_] if waiting      => String::from("Waiting for the race to start"),
+            [":new"]            => board.reset().err().unwrap_or_default(),
+            [":stats"]          => summary(&stats),
+            [":share"]          => board.share().unwrap_or_else(|_| String::from("Finish the game to share it")),
+            [":challenge", w]   => match Challenge::new(&board.dictionary, w, board.rules, board.hard_mode).map(|c| c.code()) {
+                Ok(code)    => format!("Challenge code {} (play it with --challenge {})", code, code),
+                Err(e)      => e
+            },
+            [":contrast"]       => { board.high_contrast = !board.high_contrast; String::new() },
+            [":hint"]           => match board.hint() {
+                Ok(h)   => h.to_string(),
+                Err(e)  => e
+            },
+            [":hard"]           => match board.set_hard_mode(!board.hard_mode) {
+                Ok(_)   => String::new(),
+                Err(_)  => String::from("Hard mode is locked")
+            },
+            [":theme", n]       => match n.parse::<usize>() {
+                Ok(n) if n < THEMES.len()   => { theme = n; String::new() },
+                _                           => format!("Themes are 0 to {}", THEMES.len() - 1)
+            },
+            []                  => match board.is_playing() {
+                true    => String::new(),
+                false   => board.reset().err().unwrap_or_default()
+            },
+            [word]              => match board.enter_word(word).map(|feedback| send_row(&race, &feedback)) {
+                Ok(Err(e))                      => e,
+                Ok(_) if !board.is_playing()    => record(&mut stats, stats_path.as_ref(), &board),
+                Ok(_)                           => String::new(),
+                Err(e)                          => e.to_string()
+            },
+            _                   => String::from("One word per guess")
+        };
+    }
+
+    println!();
+}
@@ -0,0 +1,62 @@
+// wordle_pipe
+//  Plain protocol for scripts and bots, no terminal control codes.
+//
+//  stdin:  one guess per line
+//  stdout: the feedback pattern per accepted guess, eg. "GY..G"
+//          (G right place, Y wrong place, . absent), or
+//          "INVALID <reason>" for a rejected guess, which costs no attempt;
+//          then one final line, "WIN <n>/<attempts>" or "LOSE <answer>"
+//
+//  exit:   0 win, 1 lose, 2 invalid input (bad options, or input ended
+//          before the game did)
+
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+
+use wordle_nova::{BoardStatus, LaunchOptions};
+
+const EXIT_WIN: u8 = 0;
+const EXIT_LOSE: u8 = 1;
+const EXIT_INVALID: u8 = 2;
+
+fn main() -> ExitCode {
+    let mut board = match LaunchOptions::from_args(std::env::args().skip(1)).and_then(LaunchOptions::board) {
+        Ok(b)   => b,
+        Err(e)  => {
+            eprintln!("{}", e);
+            return ExitCode::from(EXIT_INVALID);
+        }
+    };
+
+    let stdin = io::stdin();
+    let mut stdout = io::stdout().lock();
+
+    for line in stdin.lock().lines() {
+        let line = match line {
+            Ok(l)   => l,
+            Err(_)  => break
+        };
+        let guess = line.trim();
+        if guess.is_empty() {
+            continue;
+        }
+
+        let reply = match board.enter_word(guess) {
+            Ok(feedback)    => feedback.pattern(),
+            Err(e)          => format!("INVALID {}", e)
+        };
+        if writeln!(stdout, "{}", reply).and_then(|_| stdout.flush()).is_err() {
+            return ExitCode::from(EXIT_INVALID);
+        }
+
+        let code = match board.status {
+            BoardStatus::Win(n) => { let _ = writeln!(stdout, "WIN {}/{}", n + 1, board.rules.attempts); EXIT_WIN },
+            BoardStatus::Lose   => { let _ = writeln!(stdout, "LOSE {}", board.answer); EXIT_LOSE },
+            _                   => continue
+        };
+        return ExitCode::from(code);
+    }
+
+    eprintln!("wordle_pipe: Input ended before the game did");
+    ExitCode::from(EXIT_INVALID)
+}
@@ -0,0 +1,66 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use wordle_nova::{Board, Dictionary, GuessFeedback, Rules};
+
+// Runs wordle_pipe on the given input; stdout lines and the exit code
+fn pipe(args: &[&str], input: &str) -> (Vec<String>, Option<i32>) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_wordle_pipe"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    // A game that's already over stops reading, so a failed write is fine
+    let _ = child.stdin.take().unwrap().write_all(input.as_bytes());
+
+    let output = child.wait_with_output().unwrap();
+    (String::from_utf8(output.stdout).unwrap().lines().map(String::from).collect(), output.status.code())
+}
+
+fn answer(seed: u64) -> String {
+    Board::seeded(Dictionary::bundled(), Rules::default(), seed).unwrap().answer
+}
+
+#[test]
+fn win_prints_the_row_then_the_score() {
+    let answer = answer(7);
+    let (lines, code) = pipe(&["--seed", "7"], &format!("{}\n", answer.to_lowercase()));
+
+    assert_eq!(lines, ["GGGGG", "WIN 1/6"]);
+    assert_eq!(code, Some(0));
+}
+
+#[test]
+fn loss_gives_the_answer_away() {
+    let answer = answer(7);
+    let wrong = if answer == "CRANE" { "SLATE" } else { "CRANE" };
+    let pattern = GuessFeedback::score(&wrong.chars().collect::<Vec<_>>(), &answer.chars().collect::<Vec<_>>()).unwrap().pattern();
+    let (lines, code) = pipe(&["--seed", "7", "--attempts", "1"], &format!("{}\n", wrong));
+
+    assert_eq!(lines, [pattern, format!("LOSE {}", answer)]);
+    assert_eq!(code, Some(1));
+}
+
+#[test]
+fn invalid_words_cost_no_attempt() {
+    let answer = answer(7);
+    let (lines, code) = pipe(&["--seed", "7", "--attempts", "1"], &format!("QQQQQ\n\n{}\n", answer));
+
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("INVALID "), "{}", lines[0]);
+    assert_eq!(lines[1..], ["GGGGG", "WIN 1/1"]);
+    assert_eq!(code, Some(0));
+}
+
+#[test]
+fn bad_options_and_early_endings_exit_2() {
+    let (lines, code) = pipe(&["--bogus"], "");
+    assert!(lines.is_empty());
+    assert_eq!(code, Some(2));
+
+    let (lines, code) = pipe(&["--seed", "7"], "QQQQQ\n");
+    assert_eq!(lines.len(), 1);
+    assert_eq!(code, Some(2));
+}
@@ -0,0 +1,26 @@
+// Compares opening guesses against the bundled dictionary.
+//  cargo run --release --example openers -- [top N] [words...]
+//  eg. cargo run --release --example openers -- 10 slate crane
+
+use wordle_nova::{Recommender, WordList};
+
+fn main() {
+    let mut args = std::env::args().skip(1).peekable();
+    let n = match args.peek().and_then(|a| a.parse::<usize>().ok()) {
+        Some(n) => { args.next(); n },
+        None    => 10
+    };
+
+    let words = WordList::bundled();
+    let recommender = Recommender::new(&words, words.words().iter().map(String::as_str).collect());
+
+    println!("Top {} of {} words", n, words.len());
+    for (i, r) in recommender.top(n).iter().enumerate() {
+        println!("{:>3}. {}  {:.4} bits", i + 1, r.word, r.entropy);
+    }
+
+    for word in args {
+        let r = recommender.evaluate(&word);
+        println!("     {}  {:.4} bits", r.word, r.entropy);
+    }
+}
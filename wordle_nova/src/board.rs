@@ -0,0 +1,449 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::clock::Clock;
+
+use crate::daily::{Daily, Date};
+use crate::feedback::{GuessFeedback, TileState};
+use crate::hint::Hint;
+use crate::provider::AnswerProvider;
+use crate::source::WordSource;
+use crate::words::{Dictionary, WordList};
+
+// ErrorType
+pub type PrErr<T> = Result<T, String>;
+
+// GuessError
+//  Why a guess was turned away. A rejected guess never uses up a row.
+#[derive(Clone, PartialEq, Debug)]
+pub enum GuessError {
+    GameOver,
+    NotEnoughLetters,
+    TooManyLetters,
+    NotInWordList(String),
+    MissingCorrect { position: usize, letter: char },   // hard mode: a green moved
+    MissingPresent { letter: char, count: usize },      // hard mode: a revealed letter was dropped
+    OutOfTime,                                          // blitz: the clock ran out first
+    Engine(String)
+} impl fmt::Display for GuessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GuessError::GameOver                            => write!(f, "The game is over"),
+            GuessError::NotEnoughLetters                    => write!(f, "Not enough letters"),
+            GuessError::TooManyLetters                      => write!(f, "Too many letters"),
+            GuessError::NotInWordList(w)                    => write!(f, "{} is not in word list", w),
+            GuessError::MissingCorrect { position, letter } => write!(f, "{} letter must be {}", ordinal(position + 1), letter),
+            GuessError::MissingPresent { letter, count: 1 } => write!(f, "Guess must contain {}", letter),
+            GuessError::MissingPresent { letter, count }    => write!(f, "Guess must contain {} {}s", count, letter),
+            GuessError::OutOfTime                           => write!(f, "Out of time"),
+            GuessError::Engine(e)                           => write!(f, "{}", e)
+        }
+    }
+}
+
+pub(crate) fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13)    => "th",
+        (1, _)          => "st",
+        (2, _)          => "nd",
+        (3, _)          => "rd",
+        _               => "th"
+    };
+
+    format!("{}{}", n, suffix)
+}
+
+// On-screen keyboard, '.' marks an empty slot
+pub const KEYBOARD_LAYOUT: [[char; 10]; 3] = [
+    ['Q', 'W', 'E', 'R', 'T', 'Y', 'U', 'I', 'O', 'P'],
+    ['A', 'S', 'D', 'F', 'G', 'H', 'J', 'K', 'L', '.'],
+    ['Z', 'X', 'C', 'V', 'B', 'N', 'M', '.', '.', '.']
+];
+
+// Rules
+//  Word length and number of attempts; storage and layout follow these.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Rules {
+    pub length: usize,
+    pub attempts: usize
+} impl Rules {
+    pub const LENGTHS: std::ops::RangeInclusive<usize> = 4..=8;
+    pub const MAX_ATTEMPTS: usize = 20; // every row is stored and drawn
+
+    pub fn new(length: usize, attempts: usize) -> PrErr<Rules> {
+        if !Rules::LENGTHS.contains(&length) {
+            return Err(format!("implRules: Word length {} is not within 4 to 8", length));
+        }
+        if attempts == 0 {
+            return Err(String::from("implRules: Need at least one attempt"));
+        }
+        if attempts > Rules::MAX_ATTEMPTS {
+            return Err(format!("implRules: {} attempts is more than the {} allowed", attempts, Rules::MAX_ATTEMPTS));
+        }
+
+        Ok(Rules { length, attempts })
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Rules {
+        Rules { length: 5, attempts: 6 }
+    }
+}
+
+// BoardDefinition
+#[derive(Clone, PartialEq, Debug)]
+pub struct Board {
+    pub answer: String,
+    pub rules: Rules,
+    pub guesses: Vec<Vec<char>>,
+    pub correction: Vec<GuessFeedback>,
+    pub status: BoardStatus,
+    pub dictionary: Dictionary,
+    pub seed: Option<u64>, // None when the answer was set by hand
+    pub mode: GameMode,
+    pub provider: AnswerProvider, // fixed, or moved by the adversary as guesses come in
+    pub clock: Clock, // from the first letter typed; counts down in blitz
+    pub hard_mode: bool, // revealed hints must be used in later guesses
+    pub hints: Vec<(usize, Hint)>, // every hint given this game, in order, with the number of guesses in by then
+    pub high_contrast: bool, // colour-blind setting: orange and blue for right and wrong place
+
+
+    pub gui_current_loc: [usize; 2], // for front-ends only
+    pub gui_letter_hint: [TileState; 26],
+    pub gui_theme_no: usize
+
+} impl Board {
+    pub fn create() -> PrErr<Board> {
+        Board::from_dictionary(Dictionary::bundled(), Rules::default())
+    }
+
+    pub fn from_dictionary(dictionary: Dictionary, rules: Rules) -> PrErr<Board> {
+        Board::from_source(dictionary, rules, &mut WordSource::from_entropy())
+    }
+
+    pub fn from_source(dictionary: Dictionary, rules: Rules, source: &mut WordSource) -> PrErr<Board> {
+        Board::seeded(dictionary, rules, source.next_seed())
+    }
+
+    // The same dictionary, rules and seed always give the same answer
+    pub fn seeded(dictionary: Dictionary, rules: Rules, seed: u64) -> PrErr<Board> {
+        let dictionary = dictionary.for_length(rules.length)?;
+        let answer = WordSource::pick(&dictionary.answers, seed)?;
+        let mut board = Board::new(&answer, rules)?.with_dictionary(dictionary);
+        board.seed = Some(seed);
+
+        Ok(board)
+    }
+
+    // Everyone on the same epoch gets the same word for a given date
+    pub fn daily(dictionary: Dictionary, rules: Rules, daily: &Daily, date: Date) -> PrErr<Board> {
+        let dictionary = dictionary.for_length(rules.length)?;
+        let puzzle = daily.puzzle_number(date)?;
+        let answer = daily.answer(&dictionary.answers, puzzle)?;
+        let mut board = Board::new(&answer, rules)?.with_dictionary(dictionary);
+        board.mode = GameMode::Daily(puzzle);
+
+        Ok(board)
+    }
+
+    // Absurdle: no answer is picked, the adversary dodges every guess it can
+    pub fn adversarial(dictionary: Dictionary, rules: Rules) -> PrErr<Board> {
+        let dictionary = dictionary.for_length(rules.length)?;
+        let first = dictionary.answers.get(0).ok_or(String::from("implBoard: No answers to play against"))?.to_string();
+        let mut board = Board::new(&first, rules)?.with_dictionary(dictionary);
+        board.provider = AnswerProvider::adversarial(&board.dictionary.answers);
+
+        Ok(board)
+    }
+
+    // Rules built by hand are checked as Rules::new would
+    pub fn new(answer: &str, rules: Rules) -> PrErr<Board> {
+        let rules = Rules::new(rules.length, rules.attempts)?;
+        if answer.chars().count() != rules.length {
+            return Err(format!("implBoard: Answer {} is not {} letters long", answer, rules.length));
+        }
+        if !answer.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(format!("implBoard: Answer {} is not all letters", answer));
+        }
+
+        Ok(Board {
+            answer: answer.to_ascii_uppercase(),
+            rules,
+            guesses: vec![vec!['·'; rules.length]; rules.attempts],
+            correction: vec![GuessFeedback::pending(rules.length); rules.attempts],
+            status: BoardStatus::NotStarted,
+            dictionary: Dictionary::bundled(),
+            seed: None,
+            mode: GameMode::FreePlay,
+            provider: AnswerProvider::Fixed,
+            clock: Clock::default(),
+            hard_mode: false,
+            hints: Vec::new(),
+            high_contrast: false,
+
+            gui_current_loc: [0, 0],
+            gui_letter_hint: [TileState::Pending; 26],
+            gui_theme_no: 0
+        })
+    }
+
+    // Default attempts, length taken from the answer
+    pub fn with_answer(answer: &str) -> PrErr<Board> {
+        Board::new(answer, Rules { length: answer.chars().count(), ..Rules::default() })
+    }
+
+    pub fn with_dictionary(mut self, dictionary: Dictionary) -> Board {
+        self.dictionary = dictionary;
+        self
+    }
+
+    // Accept guesses from a different list (the answer is always accepted)
+    pub fn with_accepted(mut self, accepted: Arc<WordList>) -> Board {
+        self.dictionary.accepted = accepted;
+        self
+    }
+
+    // New Game: a fresh free-play answer under the same rules and dictionary,
+    // keeping the player's settings (hard mode, contrast, theme)
+    pub fn reset(&mut self) -> PrErr<()> {
+        self.reset_from(&mut WordSource::from_entropy())
+    }
+
+    pub fn reset_from(&mut self, source: &mut WordSource) -> PrErr<()> {
+        let mut fresh = match self.provider {
+            AnswerProvider::Fixed               => Board::from_source(self.dictionary.clone(), self.rules, source)?,
+            AnswerProvider::Adversarial { .. }  => Board::adversarial(self.dictionary.clone(), self.rules)?
+        };
+        fresh.hard_mode = self.hard_mode;
+        fresh.high_contrast = self.high_contrast;
+        fresh.clock = self.clock.reset();
+        fresh.gui_theme_no = self.gui_theme_no;
+
+        *self = fresh;
+        Ok(())
+    }
+
+    // Next level of help; recorded so hinted games can be told apart
+    pub fn hint(&mut self) -> PrErr<Hint> {
+        self.give_hint(Hint::next)
+    }
+
+    // For a UI thread: never waits on the opening suggestion, see Hint::next_quick
+    pub fn quick_hint(&mut self) -> PrErr<Hint> {
+        self.give_hint(Hint::next_quick)
+    }
+
+    fn give_hint(&mut self, next: fn(&Board) -> Hint) -> PrErr<Hint> {
+        if !self.is_playing() {
+            return Err(String::from("implBoard: No hints once the game is over"));
+        }
+
+        let hint = next(self);
+        if let Hint::Reveal { position, letter } = hint {
+            self.provider.reveal(position, letter);
+        }
+
+        let row = self.correction.iter().filter(|f| f.is_scored()).count();
+        self.hints.push((row, hint.clone()));
+        Ok(hint)
+    }
+
+    // Only before the first guess, so hard mode cannot be switched on mid-game
+    pub fn set_hard_mode(&mut self, on: bool) -> PrErr<()> {
+        match self.status {
+            BoardStatus::NotStarted => { self.hard_mode = on; Ok(()) },
+            _                       => Err(String::from("implBoard: Hard mode is locked once a guess is made"))
+        }
+    }
+
+    pub fn is_playing(&self) -> bool {
+        matches!(self.status, BoardStatus::NotStarted | BoardStatus::OnGoing(_))
+    }
+
+    // Input, shared by every front-end so they all edit the row the same way
+    pub fn push_letter(&mut self, c: char) {
+        let length = self.rules.length;
+        if self.is_playing() {
+            self.clock.start(Instant::now());
+        }
+        if self.is_playing() && self.gui_current_loc[1] < length {
+            self.guesses[self.gui_current_loc[0]][self.gui_current_loc[1]] = c.to_ascii_uppercase();
+            self.gui_current_loc[1] = match self.gui_current_loc[1] + 1 {
+                x if x < length => x,
+                _               => length
+            };
+        }
+    }
+
+    pub fn pop_letter(&mut self) {
+        if self.is_playing() {
+            self.gui_current_loc[1] = self.gui_current_loc[1].saturating_sub(1);
+            self.guesses[self.gui_current_loc[0]][self.gui_current_loc[1]] = '·';
+        }
+    }
+
+    pub fn clear_row(&mut self) {
+        if self.is_playing() {
+            let row = self.gui_current_loc[0];
+            self.guesses[row].iter_mut().for_each(|c| *c = '·');
+            self.gui_current_loc = [row, 0];
+        }
+    }
+
+    // Whole-word entry for line based front-ends: replaces the current row and submits it
+    pub fn enter_word(&mut self, word: &str) -> Result<GuessFeedback, GuessError> {
+        if !self.is_playing() {
+            return Err(GuessError::GameOver);
+        }
+        if word.chars().count() > self.rules.length {
+            return Err(GuessError::TooManyLetters);
+        }
+        if !word.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(GuessError::NotInWordList(word.to_ascii_uppercase()));
+        }
+
+        self.clear_row();
+        word.chars().for_each(|c| self.push_letter(c));
+        self.submit_row()
+    }
+
+    pub fn select(&mut self, row: usize, col: usize) {
+        if self.gui_current_loc[0] == row && col < self.rules.length {
+            self.gui_current_loc[1] = col;
+        }
+    }
+
+    pub fn submit_row(&mut self) -> Result<GuessFeedback, GuessError> {
+        let correction = self.submit_guess()?;
+        let last = self.rules.attempts - 1;
+        self.gui_current_loc = [match self.gui_current_loc[0] + 1 {x if x < last => x, _ => last}, 0];
+
+        Ok(correction)
+    }
+
+    pub fn submit_guess(&mut self) -> Result<GuessFeedback, GuessError> {
+        let current = match self.status {
+            BoardStatus::OnGoing(n) => n + 1,
+            BoardStatus::NotStarted => 0,
+            _                       => return Err(GuessError::GameOver)
+        };
+
+        let now = Instant::now();
+        if self.check_time(now) {
+            return Err(GuessError::OutOfTime);
+        }
+        self.clock.start(now);
+
+        // Rejections happen before the status moves, so no attempt is spent
+        self.check_guess(current)?;
+        let correction = self.make_correction(current).map_err(GuessError::Engine)?;
+
+        self.status = BoardStatus::OnGoing(current);
+        if correction.is_solved() {
+            self.status = BoardStatus::Win(current);
+        } else if current + 1 == self.rules.attempts { // all guesses made, and not win
+            self.status = BoardStatus::Lose;
+        }
+
+        if !self.is_playing() {
+            self.clock.stop(now);
+        }
+
+        Ok(correction)
+    }
+
+    // Blitz: ends the game as lost once the clock has run out. Front-ends
+    // call this on a timer; guesses check it themselves.
+    pub fn check_time(&mut self, now: Instant) -> bool {
+        if self.is_playing() && self.clock.is_expired(now) {
+            self.status = BoardStatus::Lose;
+            self.clock.stop(now);
+            return true;
+        }
+
+        false
+    }
+
+    pub fn check_guess(&self, current: usize) -> Result<(), GuessError> {
+        if self.guesses[current].contains(&'·') {
+            return Err(GuessError::NotEnoughLetters);
+        }
+
+        let word: String = self.guesses[current].iter().collect();
+        if word != self.answer && !self.dictionary.accepted.contains(&word) {
+            return Err(GuessError::NotInWordList(word));
+        }
+
+        match self.hard_mode {
+            true    => self.check_hard_mode(current),
+            false   => Ok(())
+        }
+    }
+
+    fn check_hard_mode(&self, current: usize) -> Result<(), GuessError> {
+        let guess = &self.guesses[current];
+
+        for row in 0..current {
+            // Greens stay put
+            for (i, tile) in self.correction[row].iter().enumerate() {
+                if tile.state == TileState::Correct && guess[i] != self.guesses[row][i] {
+                    return Err(GuessError::MissingCorrect { position: i, letter: self.guesses[row][i] });
+                }
+            }
+
+            // Every revealed copy (green or yellow) is used again somewhere
+            let mut revealed = [0usize; 26];
+            for (i, tile) in self.correction[row].iter().enumerate() {
+                if matches!(tile.state, TileState::Correct | TileState::Present) {
+                    revealed[(self.guesses[row][i] as usize) - 65] += 1;
+                }
+            }
+            for (alphano, &count) in revealed.iter().enumerate() {
+                let letter = (alphano as u8 + 65) as char;
+                if guess.iter().filter(|&&c| c == letter).count() < count {
+                    return Err(GuessError::MissingPresent { letter, count });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn make_correction(&mut self, current: usize) -> PrErr<GuessFeedback> {
+        self.provider.choose(&self.guesses[current], &mut self.answer);
+
+        let answer: Vec<char> = self.answer.chars().collect();
+        let guess = &self.guesses[current];
+        let feedback = match GuessFeedback::score(guess, &answer) {
+            Ok(f) if f.len() == self.rules.length   => f,
+            _                                       => {
+                self.correction[current] = GuessFeedback::error(self.rules.length);
+                return Err(format!("implBoard: Cannot correct {:?} against {}", guess, self.answer));
+            }
+        };
+
+        for (c, tile) in guess.iter().zip(feedback.iter()) {
+            let alphano = (*c as usize) - 65;
+            self.gui_letter_hint[alphano] = self.gui_letter_hint[alphano].max(tile.state);
+        }
+
+        self.correction[current] = feedback;
+        Ok(self.correction[current].clone())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GameMode {
+    FreePlay,
+    Daily(u32) // puzzle number
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum BoardStatus {
+    Win(usize),
+    Lose,
+    OnGoing(usize),
+    NotStarted
+}
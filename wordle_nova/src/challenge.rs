@@ -0,0 +1,152 @@
+use rand::RngCore;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::board::{Board, PrErr, Rules};
+use crate::source::WordSource;
+use crate::words::Dictionary;
+
+// Keystream for the code, mixed with a random byte so the same word gives
+// a different code each time. Like the saved answer, not encryption; the
+// point is that a code pasted in chat doesn't give the word away.
+const CODE_KEY: u64 = 0x4348_414c_4c45_4e47;
+
+// Crockford's base32: no I, L, O or U to misread
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+const HARD_MODE: u8 = 0x10;
+
+// Challenge
+//  A word set by one player for another, passed on as a short code.
+//  Code layout, before base32: a random byte, then scrambled with the
+//  keystream it picks: length (plus the hard mode bit), attempts, the
+//  letters at 5 bits each and a check byte over all of these.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Challenge {
+    pub answer: String,
+    pub rules: Rules,
+    pub hard_mode: bool
+} impl Challenge {
+    // Only words the dictionary accepts or could answer with, so the recipient could guess it
+    pub fn new(dictionary: &Dictionary, word: &str, rules: Rules, hard_mode: bool) -> PrErr<Challenge> {
+        let answer = word.trim().to_ascii_uppercase();
+        if answer.chars().count() != rules.length {
+            return Err(format!("implChallenge: {} is not {} letters long", answer, rules.length));
+        }
+        if !dictionary.accepted.contains(&answer) && !dictionary.answers.contains(&answer) {
+            return Err(format!("implChallenge: {} is not in the word list", answer));
+        }
+        if rules.attempts > u8::MAX as usize {
+            return Err(format!("implChallenge: Can't send {} attempts", rules.attempts));
+        }
+
+        Ok(Challenge { answer, rules, hard_mode })
+    }
+
+    pub fn code(&self) -> String {
+        self.code_with(WordSource::from_entropy().next_seed() as u8)
+    }
+
+    // The same nonce always gives the same code
+    pub fn code_with(&self, nonce: u8) -> String {
+        let mut bytes = vec![self.rules.length as u8 | if self.hard_mode { HARD_MODE } else { 0 }, self.rules.attempts as u8];
+        let letters: Vec<u8> = self.answer.bytes().map(|b| b - b'A').collect();
+        bytes.extend(regroup(&letters, 5, 8));
+        bytes.push(check(&bytes));
+
+        let mut wire = vec![nonce];
+        wire.extend(scramble(&bytes, nonce));
+
+        regroup(&wire, 8, 5).into_iter().map(|n| ALPHABET[n as usize] as char).collect()
+    }
+
+    // Case, dashes and spaces don't matter, and the letters Crockford
+    // leaves out are read as the digits they look like
+    pub fn decode(code: &str) -> PrErr<Challenge> {
+        let bad = || String::from("implChallenge: Not a challenge code");
+
+        let symbols = code.chars()
+            .filter(|c| !(c.is_whitespace() || *c == '-'))
+            .map(|c| match c.to_ascii_uppercase() {
+                'O'         => Some(0),
+                'I' | 'L'   => Some(1),
+                c           => ALPHABET.iter().position(|&a| a as char == c).map(|n| n as u8)
+            })
+            .collect::<Option<Vec<u8>>>()
+            .ok_or_else(bad)?;
+
+        let mut wire = regroup(&symbols, 5, 8);
+        wire.truncate(symbols.len() * 5 / 8);
+        let (&nonce, rest) = wire.split_first().ok_or_else(bad)?;
+        let bytes = scramble(rest, nonce);
+
+        let length = match bytes.first() {
+            Some(b) if b & !(HARD_MODE | 0x0f) == 0 => (b & 0x0f) as usize,
+            _                                       => return Err(bad())
+        };
+        if !Rules::LENGTHS.contains(&length) || bytes.len() != 2 + (length * 5).div_ceil(8) + 1 {
+            return Err(bad());
+        }
+        let (body, sum) = bytes.split_at(bytes.len() - 1);
+        if check(body) != sum[0] {
+            return Err(bad());
+        }
+
+        let letters = &regroup(&body[2..], 8, 5)[..length];
+        if letters.iter().any(|&n| n >= 26) {
+            return Err(bad());
+        }
+
+        let challenge = Challenge {
+            answer: letters.iter().map(|&n| (b'A' + n) as char).collect(),
+            rules: Rules::new(length, body[1] as usize).map_err(|_| bad())?,
+            hard_mode: body[0] & HARD_MODE != 0
+        };
+
+        // Padding bits and stray symbols don't survive the round trip
+        let typed: String = symbols.iter().map(|&n| ALPHABET[n as usize] as char).collect();
+        match challenge.code_with(nonce) == typed {
+            true    => Ok(challenge),
+            false   => Err(bad())
+        }
+    }
+
+    // The dictionary only matters for the games that follow
+    pub fn board(&self, dictionary: Dictionary) -> PrErr<Board> {
+        let mut board = Board::new(&self.answer, self.rules)?.with_dictionary(dictionary.for_length(self.rules.length)?);
+        board.hard_mode = self.hard_mode;
+
+        Ok(board)
+    }
+}
+
+// Same both ways
+fn scramble(bytes: &[u8], nonce: u8) -> Vec<u8> {
+    let mut key = ChaCha8Rng::seed_from_u64(CODE_KEY ^ nonce as u64);
+    bytes.iter().map(|b| b ^ (key.next_u32() as u8)).collect()
+}
+
+fn check(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0u8, |sum, &b| sum.rotate_left(3) ^ b)
+}
+
+// Repacks a stream of `from`-bit values as `to`-bit values, high bits
+// first; a value left part-filled at the end is padded with zeros
+fn regroup(values: &[u8], from: u32, to: u32) -> Vec<u8> {
+    let mut out = Vec::new();
+    let (mut held, mut bits) = (0u32, 0u32);
+    for &v in values {
+        held = (held << from) | v as u32;
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            out.push((held >> bits) as u8 & ((1 << to) - 1) as u8);
+        }
+        held &= (1 << bits) - 1;
+    }
+    if bits > 0 {
+        out.push((held << (to - bits)) as u8 & ((1 << to) - 1) as u8);
+    }
+
+    out
+}
@@ -0,0 +1,100 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+// Clock
+//  Every board times itself from the first letter typed to the end of
+//  the game. With a limit it counts down instead and the game is lost
+//  when it runs out (blitz).
+//
+//  Times are kept to the millisecond. Two clocks are equal when they are
+//  in the same state; the instant a running clock started isn't compared,
+//  so a game resumed from disk equals the one that was saved.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Clock {
+    pub limit: Option<Duration>,
+    started: Option<Instant>,
+    stopped: Option<Duration> // elapsed, once the game is over
+} impl Clock {
+    pub fn countdown(limit: Duration) -> Clock {
+        Clock { limit: Some(limit), ..Clock::default() }
+    }
+
+    // Same limit, not started
+    pub fn reset(&self) -> Clock {
+        Clock { limit: self.limit, ..Clock::default() }
+    }
+
+    // Picks up a saved clock as if it had been running for `elapsed`
+    pub fn resumed(limit: Option<Duration>, elapsed: Option<Duration>, stopped: bool, now: Instant) -> Clock {
+        match (elapsed, stopped) {
+            (Some(e), true)     => Clock { limit, started: now.checked_sub(e), stopped: Some(e) },
+            (Some(e), false)    => Clock { limit, started: Some(now.checked_sub(e).unwrap_or(now)), stopped: None },
+            (None, _)           => Clock { limit, ..Clock::default() }
+        }
+    }
+
+    // Only the first call counts
+    pub fn start(&mut self, now: Instant) {
+        if self.started.is_none() {
+            self.started = Some(now);
+        }
+    }
+
+    pub fn stop(&mut self, now: Instant) {
+        if self.stopped.is_none() {
+            let elapsed = self.elapsed(now);
+            self.stopped = Some(match self.limit {
+                Some(limit) => elapsed.min(limit),
+                None        => elapsed
+            });
+        }
+    }
+
+    pub fn is_started(&self) -> bool {
+        self.started.is_some()
+    }
+
+    pub fn is_stopped(&self) -> bool {
+        self.stopped.is_some()
+    }
+
+    pub fn elapsed(&self, now: Instant) -> Duration {
+        match (self.stopped, self.started) {
+            (Some(e), _)        => e,
+            (None, Some(s))     => millis(now.saturating_duration_since(s)),
+            (None, None)        => Duration::ZERO
+        }
+    }
+
+    // Final time of a finished game
+    pub fn time(&self) -> Option<Duration> {
+        self.stopped
+    }
+
+    pub fn remaining(&self, now: Instant) -> Option<Duration> {
+        self.limit.map(|limit| limit.saturating_sub(self.elapsed(now)))
+    }
+
+    pub fn is_expired(&self, now: Instant) -> bool {
+        self.remaining(now) == Some(Duration::ZERO)
+    }
+} impl PartialEq for Clock {
+    fn eq(&self, other: &Clock) -> bool {
+        self.limit == other.limit && self.started.is_some() == other.started.is_some() && self.stopped == other.stopped
+    }
+}
+
+fn millis(d: Duration) -> Duration {
+    Duration::from_millis(d.as_millis() as u64)
+}
+
+// Stopwatch
+//  Displays a duration as m:ss.t, eg. 1:07.3
+pub struct Stopwatch(pub Duration);
+
+impl fmt::Display for Stopwatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tenths = self.0.as_millis() / 100;
+        write!(f, "{}:{:02}.{}", tenths / 600, (tenths / 10) % 60, tenths % 10)
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::board::PrErr;
+use crate::words::WordList;
+
+// Date
+//  A calendar day (proleptic Gregorian), enough for puzzle numbering.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Date {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32
+} impl Date {
+    pub fn new(year: i32, month: u32, day: u32) -> PrErr<Date> {
+        let date = Date { year, month, day };
+        match Date::from_days(date.days()) == date {
+            true    => Ok(date),
+            false   => Err(format!("implDate: {} is not a calendar day", date))
+        }
+    }
+
+    // YYYY-MM-DD
+    pub fn parse(text: &str) -> PrErr<Date> {
+        let parts: Vec<&str> = text.trim().split('-').collect();
+        if parts.len() != 3 {
+            return Err(format!("implDate: Expected YYYY-MM-DD, got {:?}", text));
+        }
+
+        let bad = |e: std::num::ParseIntError| format!("implDate: {:?}: {}", text, e);
+        Date::new(parts[0].parse().map_err(bad)?, parts[1].parse().map_err(bad)?, parts[2].parse().map_err(bad)?)
+    }
+
+    pub fn today_utc() -> Date {
+        let secs = match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(d)   => d.as_secs() as i64,
+            Err(e)  => -(e.duration().as_secs() as i64)
+        };
+
+        Date::from_days(secs.div_euclid(86_400))
+    }
+
+    // Days since 1970-01-01 (Howard Hinnant's days_from_civil)
+    pub fn days(&self) -> i64 {
+        let y = self.year as i64 - (self.month <= 2) as i64;
+        let era = y.div_euclid(400);
+        let yoe = y - era * 400;
+        let mp = (self.month as i64 + 9) % 12;
+        let doy = (153 * mp + 2) / 5 + self.day as i64 - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+        era * 146_097 + doe - 719_468
+    }
+
+    pub fn from_days(days: i64) -> Date {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+
+        Date { year: (yoe + era * 400 + (month <= 2) as i64) as i32, month, day }
+    }
+} impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+// Daily
+//  Puzzle #0 falls on the epoch and the number goes up by one a day.
+//  Answers walk a fixed shuffle of the answer list, so every install with
+//  the same list and epoch agrees on the word, and none repeats until
+//  the list runs out.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Daily {
+    pub epoch: Date
+} impl Daily {
+    // Shuffle seed, fixed so the schedule never moves under players
+    const ORDER_SEED: u64 = 0x00DA_1173_A05E;
+
+    pub fn new(epoch: Date) -> Daily {
+        Daily { epoch }
+    }
+
+    pub fn puzzle_number(&self, date: Date) -> PrErr<u32> {
+        match u32::try_from(date.days() - self.epoch.days()) {
+            Ok(n)   => Ok(n),
+            Err(_)  => Err(format!("implDaily: {} is before the epoch {}", date, self.epoch))
+        }
+    }
+
+    pub fn answer(&self, answers: &WordList, puzzle: u32) -> PrErr<String> {
+        if answers.is_empty() {
+            return Err(String::from("implDaily: No answers to choose from"));
+        }
+
+        let mut order: Vec<usize> = (0..answers.len()).collect();
+        order.shuffle(&mut ChaCha8Rng::seed_from_u64(Daily::ORDER_SEED));
+
+        match answers.get(order[puzzle as usize % order.len()]) {
+            Some(word)  => Ok(String::from(word)),
+            None        => Err(String::from("implDaily: Answer index out of range"))
+        }
+    }
+}
+
+impl Default for Daily {
+    fn default() -> Daily {
+        Daily::new(Date { year: 2021, month: 6, day: 19 })
+    }
+}
@@ -0,0 +1,171 @@
+use crate::board::{PrErr, Rules};
+
+// TileState
+//  Declared in order of how much a tile tells you, so `max` picks the
+//  best hint seen for a letter (Error sits last and is never a hint).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Default)]
+pub enum TileState {
+    #[default]
+    Pending,    // not calculated yet
+    Absent,     // not found (or every copy in the answer is already accounted for)
+    Present,    // found in wrong place
+    Correct,    // found in right place
+    Error
+}
+
+// TileFeedback
+//  One scored tile: its state, and how many times the guessed letter
+//  occurs in the answer (0 when it does not occur at all; None when
+//  only the colour is known, as for a row read back from a pattern).
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct TileFeedback {
+    pub state: TileState,
+    pub answer_count: Option<u8>
+} impl TileFeedback {
+    pub fn new(state: TileState, answer_count: u8) -> TileFeedback {
+        TileFeedback { state, answer_count: Some(answer_count) }
+    }
+
+    pub fn colour_only(state: TileState) -> TileFeedback {
+        TileFeedback { state, answer_count: None }
+    }
+
+    pub fn is_scored(&self) -> bool {
+        matches!(self.state, TileState::Absent | TileState::Present | TileState::Correct)
+    }
+}
+
+// GuessFeedback
+//  A whole row of tiles for one guess.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct GuessFeedback {
+    tiles: Vec<TileFeedback>
+} impl GuessFeedback {
+    pub fn new(tiles: Vec<TileFeedback>) -> GuessFeedback {
+        GuessFeedback { tiles }
+    }
+
+    // Scores a guess against an answer, both upper-case and the same length
+    pub fn score(guess: &[char], answer: &[char]) -> PrErr<GuessFeedback> {
+        if guess.len() != answer.len() || !guess.iter().chain(answer.iter()).all(|c| c.is_ascii_uppercase()) {
+            return Err(format!("implGuessFeedback: Cannot score {:?} against {:?}", guess, answer));
+        }
+
+        let guess: Vec<u8> = guess.iter().map(|&c| c as u8).collect();
+        let answer: Vec<u8> = answer.iter().map(|&c| c as u8).collect();
+        let mut states = vec![TileState::Absent; guess.len()];
+        score_into(&guess, &answer, &mut states);
+
+        let tiles = guess.iter().zip(states).map(
+            |(g, state)| TileFeedback::new(state, answer.iter().filter(|a| *a == g).count() as u8)
+        ).collect();
+
+        Ok(GuessFeedback { tiles })
+    }
+
+    // Back from pattern(); the letter counts aren't in a pattern, so they are left unknown
+    pub fn from_pattern(pattern: &str) -> PrErr<GuessFeedback> {
+        let tiles = pattern.chars().map(|c| match c {
+            'G' => Ok(TileState::Correct),
+            'Y' => Ok(TileState::Present),
+            '.' => Ok(TileState::Absent),
+            '-' => Ok(TileState::Pending),
+            '!' => Ok(TileState::Error),
+            _   => Err(format!("implGuessFeedback: Bad pattern {:?}", pattern))
+        }.map(TileFeedback::colour_only)).collect::<PrErr<Vec<TileFeedback>>>()?;
+
+        Ok(GuessFeedback { tiles })
+    }
+
+    pub fn pending(length: usize) -> GuessFeedback {
+        GuessFeedback { tiles: vec![TileFeedback::default(); length] }
+    }
+
+    pub fn error(length: usize) -> GuessFeedback {
+        GuessFeedback { tiles: vec![TileFeedback::new(TileState::Error, 0); length] }
+    }
+
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    pub fn tiles(&self) -> &[TileFeedback] {
+        &self.tiles
+    }
+
+    pub fn tile(&self, i: usize) -> TileFeedback {
+        self.tiles[i]
+    }
+
+    pub fn state(&self, i: usize) -> TileState {
+        self.tiles[i].state
+    }
+
+    pub fn states(&self) -> Vec<TileState> {
+        self.tiles.iter().map(|t| t.state).collect()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &TileFeedback> {
+        self.tiles.iter()
+    }
+
+    pub fn is_scored(&self) -> bool {
+        !self.tiles.is_empty() && self.tiles.iter().all(TileFeedback::is_scored)
+    }
+
+    pub fn is_solved(&self) -> bool {
+        !self.tiles.is_empty() && self.tiles.iter().all(|t| t.state == TileState::Correct)
+    }
+
+    // Compact form, eg. "GY..G": G right place, Y wrong place, . absent
+    // (- for a tile not scored yet, ! for an error)
+    pub fn pattern(&self) -> String {
+        self.tiles.iter().map(|t| match t.state {
+            TileState::Correct  => 'G',
+            TileState::Present  => 'Y',
+            TileState::Absent   => '.',
+            TileState::Pending  => '-',
+            TileState::Error    => '!'
+        }).collect()
+    }
+}
+
+// Longest word any Rules allow, sizes the scoring buffers
+pub(crate) const MAX_LENGTH: usize = *Rules::LENGTHS.end();
+
+// Scoring, shared by the board, the solver and anything else that needs it.
+//  Working:
+//      Exact matches are claimed first, then Present is handed out
+//      left to right from the answer letters still unmatched, so a
+//      letter is never reported more often than the answer holds it.
+//
+//      answer_count rides along on every tile whose letter occurs
+//      in the answer, eg. EERIE scores each E with answer_count 3.
+//
+//  guess and answer are upper-case ASCII of equal length; states is
+//  overwritten with one state per tile.
+pub(crate) fn score_into(guess: &[u8], answer: &[u8], states: &mut [TileState]) {
+    // Pass 1: exact matches, and count the answer letters they leave over
+    let mut unmatched = [0u8; 26];
+    for i in 0..guess.len() {
+        if guess[i] == answer[i] {
+            states[i] = TileState::Correct;
+        } else {
+            states[i] = TileState::Absent;
+            unmatched[(answer[i] - b'A') as usize] += 1;
+        }
+    }
+
+    // Pass 2: a misplaced letter only goes Present while unmatched copies remain
+    for i in 0..guess.len() {
+        let alphano = (guess[i] - b'A') as usize;
+        if states[i] == TileState::Absent && unmatched[alphano] > 0 {
+            unmatched[alphano] -= 1;
+            states[i] = TileState::Present;
+        }
+    }
+}
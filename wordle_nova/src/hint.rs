@@ -0,0 +1,70 @@
+use std::fmt;
+
+use crate::board::{ordinal, Board};
+use crate::feedback::TileState;
+use crate::recommend::{Recommendation, Recommender};
+use crate::solver::Solver;
+
+// Hint
+//  Help escalates each time one is asked for: first how many words are
+//  left, then a suggested guess, then letters of the answer one position
+//  at a time.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Hint {
+    Candidates(usize),
+    Suggest(String),
+    Reveal { position: usize, letter: char }
+} impl Hint {
+    // The hint that follows those already given on this board
+    pub fn next(board: &Board) -> Hint {
+        Hint::next_with(board, true)
+    }
+
+    // As next, but a letter is revealed rather than wait for the opening
+    // suggestion while it's still being ranked
+    pub fn next_quick(board: &Board) -> Hint {
+        Hint::next_with(board, false)
+    }
+
+    fn next_with(board: &Board, wait: bool) -> Hint {
+        match board.hints.len() {
+            0   => Hint::Candidates(Solver::from_board(board).candidates().len()),
+            1   => match Hint::suggestion(board, wait) {
+                Some(r) => Hint::Suggest(r.word),
+                None    => Hint::reveal(board)
+            },
+            _   => Hint::reveal(board)
+        }
+    }
+
+    // Before any guess the board adds nothing, so the dictionary's opening will do
+    fn suggestion(board: &Board, wait: bool) -> Option<Recommendation> {
+        match (board.correction.iter().any(|row| row.is_scored()), wait) {
+            (true, _)       => Recommender::from_board(board).top(1).pop(),
+            (false, true)   => Recommender::opening(&board.dictionary),
+            (false, false)  => Recommender::ready_opening(&board.dictionary)
+        }
+    }
+
+    // First position neither solved on the board nor revealed already
+    fn reveal(board: &Board) -> Hint {
+        let known = |i: usize| {
+            board.correction.iter().any(|row| row.is_scored() && row.state(i) == TileState::Correct) ||
+            board.hints.iter().any(|(_, h)| matches!(h, Hint::Reveal { position, .. } if *position == i))
+        };
+
+        match (0..board.rules.length).find(|&i| !known(i)) {
+            Some(i) => Hint::Reveal { position: i, letter: board.answer.chars().nth(i).unwrap_or('?') },
+            None    => Hint::Candidates(Solver::from_board(board).candidates().len())
+        }
+    }
+} impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::Candidates(1)                 => write!(f, "1 word left"),
+            Hint::Candidates(n)                 => write!(f, "{} words left", n),
+            Hint::Suggest(word)                 => write!(f, "Try {}", word),
+            Hint::Reveal { position, letter }   => write!(f, "{} letter is {}", ordinal(position + 1), letter)
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use std::time::Duration;
+
+use crate::board::{Board, GameMode, PrErr, Rules};
+use crate::challenge::Challenge;
+use crate::clock::Clock;
+use crate::daily::{Daily, Date};
+use crate::multi::MultiBoard;
+use crate::race::{Race, RaceClient, ToPlayer};
+use crate::source::WordSource;
+use crate::speedrun::Speedrun;
+use crate::words::Dictionary;
+
+// LaunchOptions
+//  Command line options shared by every front-end.
+//  --daily             play today's shared puzzle instead of free-play
+//  --adversarial       Absurdle: the answer dodges your guesses for as long as it can
+//  --epoch <date>      day of daily puzzle #0, as YYYY-MM-DD
+//  --answers <file>    draw the answer from this list instead of the bundled one
+//  --accepted <file>   accept guesses from this list instead of the bundled one
+//  --seed <n>          replay the free-play game that seed produced
+//  --length <n>        letters per word, 4 to 8
+//  --attempts <n>      number of guesses allowed, up to 20
+//  --boards <n>        play 2, 4 or 8 boards at once (7, 9 or 13 attempts unless given)
+//  --blitz <seconds>   the game is lost if not solved in time
+//  --speedrun <n>      solve n seeded words back to back against the clock
+//  --challenge <code>  play the word a friend set; the code also sets length, attempts and hard mode
+//  --race <address>    race others on the same word through a host (see wordle_host), eg. localhost:7878
+//  --name <name>       what the others see you as in a race
+pub struct LaunchOptions {
+    pub dictionary: Dictionary,
+    pub rules: Rules,
+    pub daily: Option<Daily>,
+    pub seed: Option<u64>,
+    pub boards: usize,
+    pub adversarial: bool,
+    pub blitz: Option<Duration>,
+    pub speedrun: Option<usize>,
+    pub challenge: Option<Challenge>,
+    pub race: Option<String>,
+    pub name: String
+} impl LaunchOptions {
+    // Arguments after the program name
+    pub fn from_args<I: Iterator<Item = String>>(mut args: I) -> PrErr<LaunchOptions> {
+        let mut options = LaunchOptions {
+            dictionary: Dictionary::bundled(),
+            rules: Rules::default(),
+            daily: None,
+            seed: None,
+            boards: 1,
+            adversarial: false,
+            blitz: None,
+            speedrun: None,
+            challenge: None,
+            race: None,
+            name: String::new()
+        };
+        let mut attempts = None;
+
+        while let Some(arg) = args.next() {
+            if arg == "--daily" {
+                options.daily = Some(options.daily.unwrap_or_default());
+                continue;
+            }
+            if arg == "--adversarial" {
+                options.adversarial = true;
+                continue;
+            }
+
+            let value = match arg.as_str() {
+                "--epoch" | "--answers" | "--accepted" | "--seed" | "--length" | "--attempts" | "--boards" | "--blitz" | "--speedrun" | "--challenge" | "--race" | "--name"
+                    => args.next().ok_or(format!("implLaunchOptions: {} needs a value", arg))?,
+                _   => return Err(format!("implLaunchOptions: Unknown option {}", arg))
+            };
+            let number = |value: &str| value.parse::<u64>().map_err(|e| format!("implLaunchOptions: Bad {} {}: {}", arg, value, e));
+
+            match arg.as_str() {
+                "--epoch"       => options.daily = Some(Daily::new(Date::parse(&value)?)),
+                "--answers"     => options.dictionary = options.dictionary.with_answers_file(&value)?,
+                "--accepted"    => options.dictionary = options.dictionary.with_accepted_file(&value)?,
+                "--length"      => options.rules = Rules::new(number(&value)? as usize, options.rules.attempts)?,
+                "--attempts"    => attempts = Some(number(&value)? as usize),
+                "--boards"      => options.boards = match number(&value)? as usize {
+                    n if MultiBoard::COUNTS.contains(&n)    => n,
+                    n                                       => return Err(format!("implLaunchOptions: Can't play {} boards, only 2, 4 or 8", n))
+                },
+                "--blitz"       => options.blitz = match number(&value)? {
+                    0   => return Err(String::from("implLaunchOptions: --blitz needs at least one second")),
+                    s   => Some(Duration::from_secs(s))
+                },
+                "--speedrun"    => options.speedrun = match number(&value)? as usize {
+                    0   => return Err(String::from("implLaunchOptions: --speedrun needs at least one word")),
+                    n   => Some(n)
+                },
+                "--challenge"   => options.challenge = Some(Challenge::decode(&value)?),
+                "--race"        => options.race = Some(value),
+                "--name"        => options.name = value,
+                _               => options.seed = Some(number(&value)?)
+            };
+        }
+
+        if options.boards > 1 && options.daily.is_some() {
+            return Err(String::from("implLaunchOptions: No daily puzzle for more than one board"));
+        }
+        if options.adversarial && (options.daily.is_some() || options.seed.is_some() || options.boards > 1) {
+            return Err(String::from("implLaunchOptions: --adversarial picks no answer, it can't be combined with --daily, --seed or --boards"));
+        }
+        if options.speedrun.is_some() && (options.daily.is_some() || options.adversarial || options.boards > 1 || options.blitz.is_some()) {
+            return Err(String::from("implLaunchOptions: --speedrun can't be combined with --daily, --adversarial, --boards or --blitz"));
+        }
+        if options.blitz.is_some() && options.boards > 1 {
+            return Err(String::from("implLaunchOptions: --blitz is for a single board"));
+        }
+        if options.challenge.is_some() && (options.daily.is_some() || options.adversarial || options.seed.is_some() || options.boards > 1 || options.speedrun.is_some()) {
+            return Err(String::from("implLaunchOptions: --challenge sets the answer, it can't be combined with --daily, --adversarial, --seed, --boards or --speedrun"));
+        }
+        if options.race.is_some() && (options.daily.is_some() || options.adversarial || options.seed.is_some() || options.boards > 1 ||
+            options.speedrun.is_some() || options.challenge.is_some() || options.blitz.is_some()) {
+            return Err(String::from("implLaunchOptions: The host picks the word for a --race, it can't be combined with other game modes"));
+        }
+        let attempts = attempts.unwrap_or(MultiBoard::attempts_for(options.boards));
+        options.rules = match &options.challenge {
+            Some(challenge) => challenge.rules,
+            None            => Rules::new(options.rules.length, attempts)?
+        };
+
+        Ok(options)
+    }
+
+    pub fn board(self) -> PrErr<Board> {
+        if self.boards > 1 {
+            return Err(format!("implLaunchOptions: {} is not available here", MultiBoard::name(self.boards)));
+        }
+        if self.speedrun.is_some() {
+            return Err(String::from("implLaunchOptions: Speedruns are not available here"));
+        }
+        if self.race.is_some() {
+            return Err(String::from("implLaunchOptions: Races are not available here"));
+        }
+
+        let mut board = match (self.challenge, self.daily, self.seed) {
+            _ if self.adversarial       => Board::adversarial(self.dictionary, self.rules)?,
+            (Some(challenge), _, _)     => challenge.board(self.dictionary)?,
+            (None, Some(daily), _)      => Board::daily(self.dictionary, self.rules, &daily, Date::today_utc())?,
+            (None, None, Some(seed))    => Board::seeded(self.dictionary, self.rules, seed)?,
+            (None, None, None)          => Board::from_dictionary(self.dictionary, self.rules)?
+        };
+        if let Some(limit) = self.blitz {
+            board.clock = Clock::countdown(limit);
+        }
+
+        Ok(board)
+    }
+
+    // --seed picks the words, otherwise a fresh seed does
+    pub fn speedrun(self) -> PrErr<Speedrun> {
+        let words = self.speedrun.ok_or(String::from("implLaunchOptions: Not a speedrun"))?;
+        let seed = self.seed.unwrap_or_else(|| WordSource::from_entropy().next_seed());
+
+        Speedrun::new(self.dictionary, self.rules, words, seed)
+    }
+
+    // Joins the race host; the rules and the word come from there.
+    // on_message gets everything the host sends after that (see RaceClient).
+    pub fn race<F>(self, on_message: F) -> PrErr<(RaceClient, Race, Board)>
+    where F: FnMut(PrErr<ToPlayer>) + Send + 'static {
+        let address = self.race.ok_or(String::from("implLaunchOptions: Not a race"))?;
+        let (client, race) = RaceClient::join(&address, &self.name, on_message)?;
+        let board = race.board(self.dictionary)?;
+
+        Ok((client, race, board))
+    }
+
+    pub fn multi_board(self) -> PrErr<MultiBoard> {
+        match self.seed {
+            Some(seed)  => MultiBoard::seeded(self.dictionary, self.rules, self.boards, seed),
+            None        => MultiBoard::from_dictionary(self.dictionary, self.rules, self.boards)
+        }
+    }
+
+    // Picks a saved game back up if it is unfinished and is the kind of game
+    // these options ask for; otherwise a new board that keeps its settings
+    pub fn resume(self, saved: Option<Board>) -> PrErr<Board> {
+        let saved = match saved {
+            Some(s) => s,
+            None    => return self.board()
+        };
+
+        let mode = match &self.daily {
+            Some(daily) => GameMode::Daily(daily.puzzle_number(Date::today_utc())?),
+            None        => GameMode::FreePlay
+        };
+        let wanted = saved.rules == self.rules && saved.mode == mode && saved.provider.is_adversarial() == self.adversarial &&
+            saved.clock.limit == self.blitz && self.seed.is_none_or(|seed| saved.seed == Some(seed)) &&
+            self.challenge.as_ref().is_none_or(|challenge| saved.seed.is_none() && saved.answer == challenge.answer);
+        if saved.is_playing() && wanted {
+            return Ok(saved);
+        }
+
+        // A challenge keeps the hard mode it was sent with
+        let challenged = self.challenge.is_some();
+        let mut board = self.board()?;
+        if !challenged {
+            board.hard_mode = saved.hard_mode;
+        }
+        board.high_contrast = saved.high_contrast;
+        board.gui_theme_no = saved.gui_theme_no;
+
+        Ok(board)
+    }
+}
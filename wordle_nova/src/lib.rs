@@ -0,0 +1,42 @@
+// wordle_nova
+//  The game engine: board, scoring, word selection and the solver.
+//  No GUI toolkit in here, front-ends (see ../gui) sit on top of this crate.
+
+mod board;
+mod challenge;
+mod clock;
+mod daily;
+mod feedback;
+mod hint;
+mod launch;
+mod multi;
+mod provider;
+mod race;
+mod recommend;
+mod save;
+mod share;
+mod solver;
+mod source;
+mod speedrun;
+mod stats;
+mod store;
+mod theme;
+mod words;
+
+pub use board::{Board, BoardStatus, GameMode, GuessError, PrErr, Rules, KEYBOARD_LAYOUT};
+pub use challenge::Challenge;
+pub use clock::{Clock, Stopwatch};
+pub use daily::{Daily, Date};
+pub use feedback::{GuessFeedback, TileFeedback, TileState};
+pub use hint::Hint;
+pub use launch::LaunchOptions;
+pub use multi::MultiBoard;
+pub use provider::AnswerProvider;
+pub use race::{Race, RaceClient, RaceHost, Racer, ToHost, ToPlayer};
+pub use recommend::{Recommendation, Recommender};
+pub use solver::Solver;
+pub use source::WordSource;
+pub use speedrun::Speedrun;
+pub use stats::Stats;
+pub use theme::{tile_colour, Rgb, CONTRAST_CORRECT, CONTRAST_PRESENT, THEMES};
+pub use words::{Dictionary, WordList};
@@ -0,0 +1,197 @@
+use crate::board::{Board, BoardStatus, GuessError, PrErr, Rules};
+use crate::feedback::{GuessFeedback, TileState};
+use crate::source::WordSource;
+use crate::words::Dictionary;
+
+// MultiBoard
+//  Dordle, Quordle and Octordle: every guess is played on all boards still
+//  unsolved, each with its own answer, feedback and status. Boards only stop
+//  taking guesses once solved, so the ones in play always share a row.
+//  Input is all or nothing: a guess rejected by any board lands on none.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MultiBoard {
+    pub boards: Vec<Board>,
+    pub seed: Option<u64> // None when the answers were set by hand
+} impl MultiBoard {
+    pub const COUNTS: [usize; 3] = [2, 4, 8];
+
+    // One more guess per extra board: 7, 9 and 13
+    pub fn attempts_for(count: usize) -> usize {
+        Rules::default().attempts + count - 1
+    }
+
+    pub fn name(count: usize) -> &'static str {
+        match count {
+            1   => "Wordle",
+            2   => "Dordle",
+            4   => "Quordle",
+            8   => "Octordle",
+            _   => "Multiboard"
+        }
+    }
+
+    pub fn from_dictionary(dictionary: Dictionary, rules: Rules, count: usize) -> PrErr<MultiBoard> {
+        MultiBoard::seeded(dictionary, rules, count, WordSource::from_entropy().next_seed())
+    }
+
+    // Different answers on every board, the same ones for the same seed
+    pub fn seeded(dictionary: Dictionary, rules: Rules, count: usize, seed: u64) -> PrErr<MultiBoard> {
+        let dictionary = dictionary.for_length(rules.length)?;
+        let answers = WordSource::sample(&dictionary.answers, count, seed);
+        if answers.len() < count {
+            return Err(format!("implMultiBoard: Only {} answers for {} boards", answers.len(), count));
+        }
+
+        let mut multi = MultiBoard::new(&answers, rules)?;
+        multi.boards.iter_mut().for_each(|b| b.dictionary = dictionary.clone());
+        multi.seed = Some(seed);
+
+        Ok(multi)
+    }
+
+    pub fn new<S: AsRef<str>>(answers: &[S], rules: Rules) -> PrErr<MultiBoard> {
+        if answers.is_empty() {
+            return Err(String::from("implMultiBoard: Need at least one board"));
+        }
+
+        Ok(MultiBoard {
+            boards: answers.iter().map(|a| Board::new(a.as_ref(), rules)).collect::<PrErr<Vec<Board>>>()?,
+            seed: None
+        })
+    }
+
+    // Fresh answers, same count, rules, dictionary and settings
+    pub fn reset(&mut self) -> PrErr<()> {
+        let first = &self.boards[0];
+        let mut fresh = MultiBoard::from_dictionary(first.dictionary.clone(), first.rules, self.boards.len())?;
+        for board in fresh.boards.iter_mut() {
+            board.hard_mode = first.hard_mode;
+            board.high_contrast = first.high_contrast;
+            board.gui_theme_no = first.gui_theme_no;
+        }
+
+        *self = fresh;
+        Ok(())
+    }
+
+    pub fn rules(&self) -> Rules {
+        self.boards[0].rules
+    }
+
+    // Won once every board is solved (on the row of the last one solved),
+    // lost as soon as any board runs out of guesses
+    pub fn status(&self) -> BoardStatus {
+        let statuses: Vec<&BoardStatus> = self.boards.iter().map(|b| &b.status).collect();
+
+        if statuses.iter().any(|s| **s == BoardStatus::Lose) {
+            return BoardStatus::Lose;
+        }
+        if statuses.iter().all(|s| matches!(s, BoardStatus::NotStarted)) {
+            return BoardStatus::NotStarted;
+        }
+
+        let last = statuses.iter().filter_map(|s| match s {
+            BoardStatus::Win(n) | BoardStatus::OnGoing(n)   => Some(*n),
+            _                                               => None
+        }).max().unwrap_or(0);
+
+        match statuses.iter().all(|s| matches!(s, BoardStatus::Win(_))) {
+            true    => BoardStatus::Win(last),
+            false   => BoardStatus::OnGoing(last)
+        }
+    }
+
+    pub fn is_playing(&self) -> bool {
+        matches!(self.status(), BoardStatus::NotStarted | BoardStatus::OnGoing(_))
+    }
+
+    // The row being typed into, from any board still in play
+    pub fn current_loc(&self) -> Option<[usize; 2]> {
+        self.boards.iter().find(|b| b.is_playing()).map(|b| b.gui_current_loc)
+    }
+
+    // Per board, the best hint seen for a letter; for split keyboard tiles.
+    // Anything but a letter is pending everywhere
+    pub fn letter_hints(&self, c: char) -> Vec<TileState> {
+        if !c.is_ascii_alphabetic() {
+            return vec![TileState::Pending; self.boards.len()];
+        }
+
+        let alphano = (c.to_ascii_uppercase() as usize) - 65;
+        self.boards.iter().map(|b| b.gui_letter_hint[alphano]).collect()
+    }
+
+    // Input, forwarded to every board still in play
+    pub fn push_letter(&mut self, c: char) {
+        self.playing().for_each(|b| b.push_letter(c));
+    }
+
+    pub fn pop_letter(&mut self) {
+        self.playing().for_each(Board::pop_letter);
+    }
+
+    pub fn clear_row(&mut self) {
+        self.playing().for_each(Board::clear_row);
+    }
+
+    pub fn select(&mut self, row: usize, col: usize) {
+        self.playing().for_each(|b| b.select(row, col));
+    }
+
+    // Feedback per board, None for the boards already solved
+    pub fn submit_row(&mut self) -> Result<Vec<Option<GuessFeedback>>, GuessError> {
+        self.apply(Board::submit_row)
+    }
+
+    pub fn enter_word(&mut self, word: &str) -> Result<Vec<Option<GuessFeedback>>, GuessError> {
+        self.apply(|b| b.enter_word(word))
+    }
+
+    fn playing(&mut self) -> impl Iterator<Item = &mut Board> {
+        self.boards.iter_mut().filter(|b| b.is_playing())
+    }
+
+    // Played on copies, kept only if every board takes the guess
+    fn apply<F: FnMut(&mut Board) -> Result<GuessFeedback, GuessError>>(&mut self, mut guess: F) -> Result<Vec<Option<GuessFeedback>>, GuessError> {
+        if !self.is_playing() {
+            return Err(GuessError::GameOver);
+        }
+
+        let mut boards = self.boards.clone();
+        let feedback = boards.iter_mut()
+            .map(|b| match b.is_playing() {
+                true    => guess(b).map(Some),
+                false   => Ok(None)
+            })
+            .collect::<Result<Vec<Option<GuessFeedback>>, GuessError>>()?;
+
+        self.boards = boards;
+        Ok(feedback)
+    }
+
+    // Header with each board's score, then the boards' squares in turn
+    pub fn share(&self) -> PrErr<String> {
+        if self.is_playing() {
+            return Err(String::from("implMultiBoard: Nothing to share until the game is over"));
+        }
+
+        let scores: Vec<String> = self.boards.iter().map(|b| match b.status {
+            BoardStatus::Win(n) => (n + 1).to_string(),
+            _                   => String::from("X")
+        }).collect();
+
+        let mut out = format!("Wordle_Beta {} {}/{}", MultiBoard::name(self.boards.len()), scores.join(" "), self.rules().attempts);
+        if self.boards[0].hard_mode {
+            out.push('*');
+        }
+        out.push('\n');
+
+        for board in &self.boards {
+            let grid = board.share()?;
+            out += grid.split_once('\n').map_or("", |(_, squares)| squares);
+            out.push('\n');
+        }
+
+        Ok(out.trim_end().to_string())
+    }
+}
@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::feedback::{score_into, TileState, MAX_LENGTH};
+use crate::words::WordList;
+
+// AnswerProvider
+//  Where the board's answer comes from. Fixed is normal play: the answer
+//  is chosen up front and never changes.
+//
+//  Adversarial (Absurdle) doesn't commit to a word. It keeps every answer
+//  still consistent with the feedback given, and on each guess splits them
+//  by the feedback they would show, keeps the largest group and moves the
+//  board's answer to a word in it. Scoring then carries on as usual, so the
+//  board, solver and hints see an ordinary game. Feedback here includes the
+//  letter counts the board shows, so the group always agrees with the rows.
+//  Ties go to the group showing fewer greens, then fewer yellows.
+#[derive(Clone, PartialEq, Debug)]
+pub enum AnswerProvider {
+    Fixed,
+    Adversarial { remaining: Arc<Vec<String>> }
+} impl AnswerProvider {
+    pub fn adversarial(answers: &WordList) -> AnswerProvider {
+        AnswerProvider::Adversarial { remaining: Arc::new(answers.words().to_vec()) }
+    }
+
+    pub fn is_adversarial(&self) -> bool {
+        matches!(self, AnswerProvider::Adversarial { .. })
+    }
+
+    // Words the answer could still be, None when it is fixed
+    pub fn remaining(&self) -> Option<usize> {
+        match self {
+            AnswerProvider::Fixed                       => None,
+            AnswerProvider::Adversarial { remaining }   => Some(remaining.len())
+        }
+    }
+
+    // Before each guess is scored; may move the answer
+    pub(crate) fn choose(&mut self, guess: &[char], answer: &mut String) {
+        let remaining = match self {
+            AnswerProvider::Fixed                       => return,
+            AnswerProvider::Adversarial { remaining }   => remaining
+        };
+
+        let guess: Vec<u8> = guess.iter().map(|&c| c as u8).collect();
+        if guess.len() > MAX_LENGTH || !guess.iter().all(u8::is_ascii_uppercase) {
+            return;
+        }
+
+        let mut groups: HashMap<Vec<u8>, Vec<&String>> = HashMap::new();
+        let mut states = [TileState::Pending; MAX_LENGTH];
+        for word in remaining.iter().filter(|w| w.len() == guess.len()) {
+            score_into(&guess, word.as_bytes(), &mut states[..guess.len()]);
+            let key = states[..guess.len()].iter().zip(guess.iter()).map(
+                |(&state, &c)| state as u8 * 16 + word.bytes().filter(|&b| b == c).count() as u8
+            ).collect();
+
+            groups.entry(key).or_default().push(word);
+        }
+
+        let shown = |key: &[u8], state: TileState| key.iter().filter(|&&k| k / 16 == state as u8).count();
+        let kept = groups.into_iter().max_by(|(a_key, a), (b_key, b)| {
+            a.len().cmp(&b.len())
+                .then(shown(b_key, TileState::Correct).cmp(&shown(a_key, TileState::Correct)))
+                .then(shown(b_key, TileState::Present).cmp(&shown(a_key, TileState::Present)))
+                .then(b_key.cmp(a_key))
+        });
+
+        if let Some((_, words)) = kept {
+            let words: Vec<String> = words.into_iter().cloned().collect();
+            *answer = words[0].clone();
+            *remaining = Arc::new(words);
+        }
+    }
+
+    // A revealed letter is a promise too
+    pub(crate) fn reveal(&mut self, position: usize, letter: char) {
+        if let AnswerProvider::Adversarial { remaining } = self {
+            let kept: Vec<String> = remaining.iter().filter(|w| w.chars().nth(position) == Some(letter)).cloned().collect();
+            if !kept.is_empty() {
+                *remaining = Arc::new(kept);
+            }
+        }
+    }
+}
@@ -0,0 +1,391 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream};
+use std::sync::mpsc;
+use std::thread;
+
+use crate::board::{Board, PrErr, Rules};
+use crate::feedback::GuessFeedback;
+use crate::words::Dictionary;
+
+// Race protocol
+//  Plain text over TCP, one message per line. Players only ever send the
+//  pattern of each guess (see GuessFeedback::pattern), never its letters,
+//  so nothing on the wire gives the word away to the others.
+//
+//  player to host:
+//      JOIN <name>
+//      ROW <pattern>
+//  host to player:
+//      WELCOME <id> <seed> <length> <attempts>     (reply to JOIN)
+//      JOINED <id> <name>                          (everyone in the race, this player included)
+//      START                                       (once the race is full)
+//      ROW <id> <pattern>
+//      LEFT <id>
+//      FINISHED <id>                               (the winner, or - when nobody solved it)
+//      REFUSED <reason>                            (eg. joining a race already started)
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ToHost {
+    Join(String),
+    Row(GuessFeedback)
+} impl ToHost {
+    pub fn parse(line: &str) -> PrErr<ToHost> {
+        match line.split_once(' ').unwrap_or((line, "")) {
+            ("JOIN", name)      => Ok(ToHost::Join(name.trim().to_string())),
+            ("ROW", pattern)    => Ok(ToHost::Row(GuessFeedback::from_pattern(pattern)?)),
+            _                   => Err(format!("implToHost: Unknown message {:?}", line))
+        }
+    }
+}
+
+impl fmt::Display for ToHost {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ToHost::Join(name)      => write!(f, "JOIN {}", name),
+            ToHost::Row(feedback)   => write!(f, "ROW {}", feedback.pattern())
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ToPlayer {
+    Welcome { id: usize, seed: u64, rules: Rules },
+    Joined { id: usize, name: String },
+    Start,
+    Row { id: usize, feedback: GuessFeedback },
+    Left { id: usize },
+    Finished { winner: Option<usize> },
+    Refused(String)
+} impl ToPlayer {
+    pub fn parse(line: &str) -> PrErr<ToPlayer> {
+        let bad = || format!("implToPlayer: Bad message {:?}", line);
+        let number = |word: Option<&str>| word.and_then(|w| w.parse::<u64>().ok()).ok_or_else(bad);
+
+        let (kind, rest) = line.split_once(' ').unwrap_or((line, ""));
+        let mut words = rest.split(' ');
+        let message = match kind {
+            "WELCOME"   => ToPlayer::Welcome {
+                id: number(words.next())? as usize,
+                seed: number(words.next())?,
+                rules: Rules::new(number(words.next())? as usize, number(words.next())? as usize)?
+            },
+            "JOINED"    => match rest.split_once(' ') {
+                Some((id, name))    => ToPlayer::Joined { id: number(Some(id))? as usize, name: name.to_string() },
+                None                => return Err(bad())
+            },
+            "START"     => ToPlayer::Start,
+            "ROW"       => ToPlayer::Row { id: number(words.next())? as usize, feedback: GuessFeedback::from_pattern(words.next().unwrap_or(""))? },
+            "LEFT"      => ToPlayer::Left { id: number(words.next())? as usize },
+            "FINISHED"  => ToPlayer::Finished {
+                winner: match rest {
+                    "-" => None,
+                    id  => Some(number(Some(id))? as usize)
+                }
+            },
+            "REFUSED"   => ToPlayer::Refused(rest.to_string()),
+            _           => return Err(bad())
+        };
+
+        Ok(message)
+    }
+}
+
+impl fmt::Display for ToPlayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ToPlayer::Welcome { id, seed, rules }   => write!(f, "WELCOME {} {} {} {}", id, seed, rules.length, rules.attempts),
+            ToPlayer::Joined { id, name }           => write!(f, "JOINED {} {}", id, name),
+            ToPlayer::Start                         => write!(f, "START"),
+            ToPlayer::Row { id, feedback }          => write!(f, "ROW {} {}", id, feedback.pattern()),
+            ToPlayer::Left { id }                   => write!(f, "LEFT {}", id),
+            ToPlayer::Finished { winner: Some(id) } => write!(f, "FINISHED {}", id),
+            ToPlayer::Finished { winner: None }     => write!(f, "FINISHED -"),
+            ToPlayer::Refused(reason)               => write!(f, "REFUSED {}", reason)
+        }
+    }
+}
+
+// Racer
+//  Someone in the race as every player sees them: a name and the
+//  colours of each row they have played so far.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Racer {
+    pub id: usize,
+    pub name: String,
+    pub rows: Vec<GuessFeedback>,
+    pub left: bool
+}
+
+// Race
+//  A player's view of the race, kept up to date by applying each message
+//  from the host. Front-ends draw the opponents from this.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Race {
+    pub me: usize,
+    pub seed: u64,
+    pub rules: Rules,
+    pub racers: Vec<Racer>, // in joining order, this player included
+    pub started: bool,
+    pub over: bool,
+    pub winner: Option<usize>
+} impl Race {
+    pub fn apply(&mut self, message: &ToPlayer) {
+        match message {
+            ToPlayer::Joined { id, name }   => if self.racer(*id).is_none() {
+                self.racers.push(Racer { id: *id, name: name.clone(), rows: Vec::new(), left: false });
+            },
+            ToPlayer::Start                 => self.started = true,
+            ToPlayer::Row { id, feedback }  => if let Some(racer) = self.racers.iter_mut().find(|r| r.id == *id) {
+                racer.rows.push(feedback.clone());
+            },
+            ToPlayer::Left { id }           => if let Some(racer) = self.racers.iter_mut().find(|r| r.id == *id) {
+                racer.left = true;
+            },
+            ToPlayer::Finished { winner }   => {
+                self.over = true;
+                self.winner = *winner;
+            },
+            ToPlayer::Welcome { .. } | ToPlayer::Refused(_) => ()
+        };
+    }
+
+    pub fn racer(&self, id: usize) -> Option<&Racer> {
+        self.racers.iter().find(|r| r.id == id)
+    }
+
+    pub fn opponents(&self) -> impl Iterator<Item = &Racer> {
+        self.racers.iter().filter(move |r| r.id != self.me)
+    }
+
+    // The board everyone races on; the same word lists as the others are needed
+    pub fn board(&self, dictionary: Dictionary) -> PrErr<Board> {
+        Board::seeded(dictionary, self.rules, self.seed)
+    }
+
+    // One line on how the race stands, for a status bar
+    pub fn summary(&self) -> String {
+        match (self.over, self.winner) {
+            (true, Some(id)) if id == self.me   => String::from("You won the race!"),
+            (true, Some(id))                    => format!("{} won the race", self.racer(id).map_or("Someone", |r| r.name.as_str())),
+            (true, None)                        => String::from("Nobody solved it"),
+            (false, _) if !self.started         => format!("Waiting for players ({} in)", self.racers.len()),
+            (false, _)                          => String::from("Race on!")
+        }
+    }
+}
+
+// RaceClient
+//  A player's connection to the host. Messages after the WELCOME are read
+//  on a thread of their own and handed to the callback as they come in,
+//  ending with an Err once the host goes away.
+pub struct RaceClient {
+    stream: TcpStream
+} impl RaceClient {
+    pub fn join<F>(address: &str, name: &str, mut on_message: F) -> PrErr<(RaceClient, Race)>
+    where F: FnMut(PrErr<ToPlayer>) + Send + 'static {
+        let stream = TcpStream::connect(address).map_err(|e| format!("implRaceClient: Can't reach {}: {}", address, e))?;
+        let client = RaceClient { stream };
+        client.send(&ToHost::Join(name.trim().to_string()))?;
+
+        let mut reader = BufReader::new(client.stream.try_clone().map_err(|e| format!("implRaceClient: {}", e))?);
+        let race = match read_line(&mut reader).and_then(|line| ToPlayer::parse(&line))? {
+            ToPlayer::Welcome { id, seed, rules }   => Race { me: id, seed, rules, racers: Vec::new(), started: false, over: false, winner: None },
+            ToPlayer::Refused(reason)               => return Err(format!("implRaceClient: Refused: {}", reason)),
+            message                                 => return Err(format!("implRaceClient: Expected WELCOME, got {}", message))
+        };
+
+        thread::spawn(move || loop {
+            match read_line(&mut reader).and_then(|line| ToPlayer::parse(&line)) {
+                Ok(message) => on_message(Ok(message)),
+                Err(e)      => return on_message(Err(e))
+            }
+        });
+
+        Ok((client, race))
+    }
+
+    // The pattern of a guess just played
+    pub fn send_row(&self, feedback: &GuessFeedback) -> PrErr<()> {
+        self.send(&ToHost::Row(feedback.clone()))
+    }
+
+    fn send(&self, message: &ToHost) -> PrErr<()> {
+        writeln!(&self.stream, "{}", message).map_err(|e| format!("implRaceClient: Lost the host: {}", e))
+    }
+}
+
+impl Drop for RaceClient {
+    fn drop(&mut self) {
+        let _ = self.stream.shutdown(Shutdown::Both);
+    }
+}
+
+fn read_line<R: BufRead>(reader: &mut R) -> PrErr<String> {
+    let mut line = String::new();
+    match reader.read_line(&mut line) {
+        Ok(0)   => Err(String::from("implRace: Connection closed")),
+        Ok(_)   => Ok(line.trim_end().to_string()),
+        Err(e)  => Err(format!("implRace: {}", e))
+    }
+}
+
+// RaceHost
+//  The small process everyone connects to. It starts the race once the
+//  set number of players have joined, passes each row on to everybody,
+//  and ends it at the first solved row (or when nobody is left playing).
+//  One thread per connection feeds a single loop that owns all the state.
+pub struct RaceHost {
+    listener: TcpListener,
+    players: usize,
+    seed: u64,
+    rules: Rules
+}
+
+enum HostEvent {
+    Connected(usize, TcpStream),
+    Message(usize, PrErr<ToHost>),
+    Closed(usize)
+}
+
+struct Seat {
+    stream: TcpStream,
+    name: Option<String>, // None until the JOIN
+    rows: usize,
+    done: bool
+}
+
+impl RaceHost {
+    pub fn bind(address: &str, players: usize, seed: u64, rules: Rules) -> PrErr<RaceHost> {
+        if players == 0 {
+            return Err(String::from("implRaceHost: A race needs at least one player"));
+        }
+
+        let listener = TcpListener::bind(address).map_err(|e| format!("implRaceHost: Can't listen on {}: {}", address, e))?;
+        Ok(RaceHost { listener, players, seed, rules })
+    }
+
+    pub fn local_addr(&self) -> PrErr<SocketAddr> {
+        self.listener.local_addr().map_err(|e| format!("implRaceHost: {}", e))
+    }
+
+    // Blocks until the race is over: the winner's name, or None if nobody solved it
+    pub fn run(self) -> PrErr<Option<String>> {
+        let (events, inbox) = mpsc::channel();
+        let listener = self.listener;
+        thread::spawn(move || {
+            for (id, stream) in listener.incoming().enumerate() {
+                let stream = match stream {
+                    Ok(s)   => s,
+                    Err(_)  => continue
+                };
+                let reader = match stream.try_clone() {
+                    Ok(r)   => BufReader::new(r),
+                    Err(_)  => continue
+                };
+                if events.send(HostEvent::Connected(id, stream)).is_err() {
+                    return;
+                }
+
+                let events = events.clone();
+                thread::spawn(move || {
+                    for line in reader.lines() {
+                        let message = match line {
+                            Ok(l)   => ToHost::parse(l.trim_end()),
+                            Err(_)  => break
+                        };
+                        if events.send(HostEvent::Message(id, message)).is_err() {
+                            return;
+                        }
+                    }
+                    let _ = events.send(HostEvent::Closed(id));
+                });
+            }
+        });
+
+        let mut seats: BTreeMap<usize, Seat> = BTreeMap::new();
+        let mut started = false;
+        for event in inbox {
+            match event {
+                HostEvent::Connected(id, stream)                => {
+                    seats.insert(id, Seat { stream, name: None, rows: 0, done: false });
+                },
+                HostEvent::Message(id, Ok(ToHost::Join(name)))  => {
+                    match seats.get(&id) {
+                        Some(seat) if started    => {
+                            send(seat, &ToPlayer::Refused(String::from("The race has already started")));
+                            let _ = seat.stream.shutdown(Shutdown::Both);
+                            continue;
+                        },
+                        Some(seat) if seat.name.is_none()   => (),
+                        _                                   => continue
+                    };
+
+                    let name = match name.is_empty() {
+                        true    => format!("Player {}", id + 1),
+                        false   => name
+                    };
+                    let seat = &seats[&id];
+                    send(seat, &ToPlayer::Welcome { id, seed: self.seed, rules: self.rules });
+                    for (&other, joined) in seats.iter().filter(|(&other, _)| other != id) {
+                        if let Some(name) = &joined.name {
+                            send(seat, &ToPlayer::Joined { id: other, name: name.clone() });
+                        }
+                    }
+                    if let Some(seat) = seats.get_mut(&id) {
+                        seat.name = Some(name.clone());
+                    }
+                    broadcast(&seats, &ToPlayer::Joined { id, name });
+
+                    if seats.values().filter(|s| s.name.is_some()).count() == self.players {
+                        started = true;
+                        broadcast(&seats, &ToPlayer::Start);
+                    }
+                },
+                HostEvent::Message(id, Ok(ToHost::Row(feedback)))   => {
+                    let seat = match seats.get_mut(&id) {
+                        Some(s) if started && s.name.is_some() && !s.done   => s,
+                        _                                                   => continue
+                    };
+                    if feedback.len() != self.rules.length || !feedback.is_scored() {
+                        continue;
+                    }
+
+                    seat.rows += 1;
+                    seat.done = seat.rows == self.rules.attempts;
+                    let name = seat.name.clone();
+                    broadcast(&seats, &ToPlayer::Row { id, feedback: feedback.clone() });
+                    if feedback.is_solved() {
+                        broadcast(&seats, &ToPlayer::Finished { winner: Some(id) });
+                        return Ok(name);
+                    }
+                },
+                HostEvent::Message(_, Err(_))                   => (),
+                HostEvent::Closed(id)                           => {
+                    if let Some(Seat { name: Some(_), .. }) = seats.remove(&id) {
+                        broadcast(&seats, &ToPlayer::Left { id });
+                    }
+                }
+            };
+
+            // Everyone out of guesses (or gone)
+            if started && seats.values().filter(|s| s.name.is_some()).all(|s| s.done) {
+                broadcast(&seats, &ToPlayer::Finished { winner: None });
+                return Ok(None);
+            }
+        }
+
+        Err(String::from("implRaceHost: Stopped listening"))
+    }
+}
+
+// Write errors are left to the reading side, which sees the connection close
+fn send(seat: &Seat, message: &ToPlayer) {
+    let _ = writeln!(&seat.stream, "{}", message);
+}
+
+fn broadcast(seats: &BTreeMap<usize, Seat>, message: &ToPlayer) {
+    for seat in seats.values().filter(|s| s.name.is_some()) {
+        send(seat, message);
+    }
+}
@@ -0,0 +1,144 @@
+use std::cmp::Ordering;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use crate::board::Board;
+use crate::feedback::{score_into, TileState, MAX_LENGTH};
+use crate::solver::Solver;
+use crate::words::{Dictionary, WordList};
+
+// Slot for one dictionary's opening, filled by whoever ranks it first
+type Opening = Arc<OnceLock<Option<Recommendation>>>;
+
+// Recommendation
+//  entropy is the expected information, in bits, the guess reveals
+//  about the answer; candidate is whether the guess could itself be it.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Recommendation {
+    pub word: String,
+    pub entropy: f64,
+    pub candidate: bool
+}
+
+// Recommender
+//  Ranks guesses by the entropy of the colour pattern they would show,
+//  taken over the remaining candidates (3^length patterns, 243 for five
+//  letters). Optionally breaks ties toward words that may be the answer.
+pub struct Recommender<'a> {
+    guesses: &'a WordList,
+    candidates: Vec<&'a str>,
+    prefer_candidates: bool
+} impl<'a> Recommender<'a> {
+    pub fn new(guesses: &'a WordList, candidates: Vec<&'a str>) -> Recommender<'a> {
+        Recommender { guesses, candidates, prefer_candidates: true }
+    }
+
+    // Accepted words as guesses, the solver's candidates as possible answers
+    pub fn from_board(board: &'a Board) -> Recommender<'a> {
+        Recommender::new(&board.dictionary.accepted, Solver::from_board(board).candidates())
+    }
+
+    // Best first guess for a dictionary. With nothing guessed it depends on
+    // the word lists alone, so it's worked out once and kept; ranking every
+    // accepted word against every answer takes a noticeable while
+    pub fn opening(dictionary: &Dictionary) -> Option<Recommendation> {
+        Recommender::opening_slot(dictionary).get_or_init(
+            || Recommender::new(&dictionary.accepted, dictionary.answers.words().iter().map(String::as_str).collect()).top(1).pop()
+        ).clone()
+    }
+
+    // The opening if it's already been ranked, None rather than waiting
+    pub fn ready_opening(dictionary: &Dictionary) -> Option<Recommendation> {
+        Recommender::opening_slot(dictionary).get().cloned().flatten()
+    }
+
+    // Only the lookup is locked, the ranking happens outside
+    fn opening_slot(dictionary: &Dictionary) -> Opening {
+        static OPENINGS: Mutex<Vec<(Dictionary, Opening)>> = Mutex::new(Vec::new());
+        let mut openings = OPENINGS.lock().unwrap_or_else(|e| e.into_inner());
+
+        match openings.iter().find(|(d, _)| d == dictionary) {
+            Some((_, slot)) => slot.clone(),
+            None            => {
+                let slot = Opening::default();
+                openings.push((dictionary.clone(), slot.clone()));
+                slot
+            }
+        }
+    }
+
+    pub fn prefer_candidates(mut self, on: bool) -> Recommender<'a> {
+        self.prefer_candidates = on;
+        self
+    }
+
+    pub fn candidates(&self) -> &[&'a str] {
+        &self.candidates
+    }
+
+    pub fn entropy(&self, guess: &str) -> f64 {
+        let guess = guess.to_ascii_uppercase();
+        let guess = guess.as_bytes();
+        if self.candidates.is_empty() || guess.len() > MAX_LENGTH || !guess.iter().all(u8::is_ascii_uppercase) {
+            return 0.0;
+        }
+
+        let mut counts = vec![0u32; 3usize.pow(guess.len() as u32)];
+        let mut states = [TileState::Pending; MAX_LENGTH];
+        let mut total = 0u32;
+        for answer in &self.candidates {
+            if answer.len() != guess.len() {
+                continue;
+            }
+
+            score_into(guess, answer.as_bytes(), &mut states[..guess.len()]);
+            counts[pattern_index(&states[..guess.len()])] += 1;
+            total += 1;
+        }
+
+        let total = total as f64;
+        counts.iter().filter(|&&n| n > 0).map(|&n| {
+            let p = n as f64 / total;
+            -p * p.log2()
+        }).sum()
+    }
+
+    pub fn evaluate(&self, guess: &str) -> Recommendation {
+        let word = guess.to_ascii_uppercase();
+        Recommendation {
+            entropy: self.entropy(&word),
+            candidate: self.candidates.contains(&word.as_str()),
+            word
+        }
+    }
+
+    // Best N guesses, most informative first
+    pub fn top(&self, n: usize) -> Vec<Recommendation> {
+        let mut ranked: Vec<Recommendation> = self.guesses.words().iter().map(|w| self.evaluate(w)).collect();
+        ranked.sort_by(|a, b| self.rank(a, b));
+        ranked.truncate(n);
+
+        ranked
+    }
+
+    fn rank(&self, a: &Recommendation, b: &Recommendation) -> Ordering {
+        let by_entropy = match (a.entropy - b.entropy).abs() < 1e-9 {
+            true    => Ordering::Equal,
+            false   => b.entropy.total_cmp(&a.entropy)
+        };
+        let by_candidate = match self.prefer_candidates {
+            true    => b.candidate.cmp(&a.candidate),
+            false   => Ordering::Equal
+        };
+
+        by_entropy.then(by_candidate).then_with(|| a.word.cmp(&b.word))
+    }
+}
+
+// Base 3 over the tiles: 0 absent, 1 present, 2 correct
+fn pattern_index(states: &[TileState]) -> usize {
+    states.iter().fold(0, |n, s| n * 3 + match s {
+        TileState::Present  => 1,
+        TileState::Correct  => 2,
+        _                   => 0
+    })
+}
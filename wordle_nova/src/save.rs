@@ -0,0 +1,250 @@
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use rand::RngCore;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::board::{Board, BoardStatus, GameMode, PrErr, Rules};
+use crate::clock::Clock;
+use crate::hint::Hint;
+use crate::provider::AnswerProvider;
+use crate::store;
+use crate::words::Dictionary;
+
+const VERSION: u32 = 1;
+
+// Keystream for the stored answer. Not encryption, only enough that
+// opening the file doesn't give the word away.
+const ANSWER_KEY: u64 = 0x5745_4c4c_5f48_4944;
+
+fn obscure(word: &str) -> String {
+    let mut key = ChaCha8Rng::seed_from_u64(ANSWER_KEY);
+    word.bytes().map(|b| format!("{:02x}", b ^ (key.next_u32() as u8))).collect()
+}
+
+fn reveal(hex: &str) -> PrErr<String> {
+    let mut key = ChaCha8Rng::seed_from_u64(ANSWER_KEY);
+    let bytes = (0..hex.len()).step_by(2)
+        .map(|i| hex.get(i..i + 2).and_then(|h| u8::from_str_radix(h, 16).ok()))
+        .map(|b| b.map(|b| b ^ (key.next_u32() as u8)))
+        .collect::<Option<Vec<u8>>>()
+        .filter(|bytes| !bytes.is_empty() && bytes.iter().all(u8::is_ascii_uppercase))
+        .ok_or(String::from("implBoard: Saved answer is damaged"))?;
+
+    Ok(bytes.into_iter().map(char::from).collect())
+}
+
+// Saved games
+//  The same "key = value" text as the stats file, one guess per line with
+//  the pattern it scored. Feedback and status are stored but also worked
+//  out again on load, so a file that doesn't add up is refused instead of
+//  producing a board that disagrees with its own answer.
+//
+//  An adversarial game is replayed through a fresh adversary, which makes
+//  the same choices again. Each hint is saved with the number of guesses
+//  made before it, so revealed letters narrow the adversary at the same
+//  point in the replay as they did in the game.
+impl Board {
+    pub fn save_path() -> PrErr<PathBuf> {
+        store::data_file("game.txt")
+    }
+
+    pub fn save(&self, path: &PathBuf) -> PrErr<()> {
+        store::write(path, &self.to_save())
+    }
+
+    // None when there is no saved game
+    pub fn resume(path: &PathBuf, dictionary: Dictionary) -> PrErr<Option<Board>> {
+        match store::read(path)? {
+            Some(text)  => Board::from_save(&text, dictionary).map(Some).map_err(|e| format!("{}: {}", path.display(), e)),
+            None        => Ok(None)
+        }
+    }
+
+    pub fn to_save(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "version = {}", VERSION);
+        let _ = writeln!(out, "answer = {}", obscure(&self.answer));
+        let _ = writeln!(out, "length = {}", self.rules.length);
+        let _ = writeln!(out, "attempts = {}", self.rules.attempts);
+        if let Some(seed) = self.seed {
+            let _ = writeln!(out, "seed = {}", seed);
+        }
+        let _ = match self.mode {
+            GameMode::FreePlay  => writeln!(out, "mode = free"),
+            GameMode::Daily(n)  => writeln!(out, "mode = daily {}", n)
+        };
+        if self.provider.is_adversarial() {
+            let _ = writeln!(out, "provider = adversarial");
+        }
+        let _ = writeln!(out, "hard_mode = {}", self.hard_mode);
+        let _ = writeln!(out, "high_contrast = {}", self.high_contrast);
+        if let Some(limit) = self.clock.limit {
+            let _ = writeln!(out, "limit = {}", limit.as_millis());
+        }
+        if self.clock.is_started() {
+            let _ = match self.clock.is_stopped() {
+                true    => writeln!(out, "clock = {} stopped", self.clock.elapsed(Instant::now()).as_millis()),
+                false   => writeln!(out, "clock = {} running", self.clock.elapsed(Instant::now()).as_millis())
+            };
+        }
+        let _ = writeln!(out, "theme = {}", self.gui_theme_no);
+
+        for (row, hint) in &self.hints {
+            let _ = match hint {
+                Hint::Candidates(n)                 => writeln!(out, "hint = candidates {} after {}", n, row),
+                Hint::Suggest(word)                 => writeln!(out, "hint = suggest {} after {}", word, row),
+                Hint::Reveal { position, letter }   => writeln!(out, "hint = reveal {} {} after {}", position, letter, row)
+            };
+        }
+        for (guess, feedback) in self.guesses.iter().zip(self.correction.iter()).filter(|(_, f)| f.is_scored()) {
+            let _ = writeln!(out, "guess = {} {}", guess.iter().collect::<String>(), feedback.pattern());
+        }
+
+        let _ = match self.status {
+            BoardStatus::Win(n)     => writeln!(out, "status = win {}", n),
+            BoardStatus::Lose       => writeln!(out, "status = lose"),
+            BoardStatus::OnGoing(n) => writeln!(out, "status = ongoing {}", n),
+            BoardStatus::NotStarted => writeln!(out, "status = not_started")
+        };
+
+        out
+    }
+
+    // The dictionary isn't saved; the one the game is resumed with is used
+    pub fn from_save(text: &str, dictionary: Dictionary) -> PrErr<Board> {
+        let mut version = None;
+        let mut answer = None;
+        let (mut length, mut attempts) = (None, None);
+        let mut seed = None;
+        let mut mode = GameMode::FreePlay;
+        let mut adversarial = false;
+        let mut hard_mode = false;
+        let mut high_contrast = false;
+        let mut limit = None;
+        let mut clock = (None, false);
+        let mut theme = 0;
+        let mut hints = Vec::new();
+        let mut guesses: Vec<(String, String)> = Vec::new();
+        let mut status = None;
+
+        for (n, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let bad = || format!("implBoard: Bad saved line {}: {}", n + 1, line);
+            let (key, value) = line.split_once('=').map(|(k, v)| (k.trim(), v.trim())).ok_or_else(bad)?;
+            let words: Vec<&str> = value.split_whitespace().collect();
+            let number = |value: &str| value.parse::<u64>().map_err(|_| bad());
+            let hint = |kind: &[&str]| match kind {
+                ["candidates", c]   => Ok(Hint::Candidates(number(c)? as usize)),
+                ["suggest", w]      => Ok(Hint::Suggest(w.to_string())),
+                ["reveal", p, l]    => Ok(Hint::Reveal {
+                    position: number(p)? as usize,
+                    letter: l.chars().next().ok_or_else(bad)?
+                }),
+                _                   => Err(bad())
+            };
+
+            match (key, words.as_slice()) {
+                ("version", [v])                => version = Some(number(v)?),
+                ("answer", [a])                 => answer = Some(reveal(a)?),
+                ("length", [l])                 => length = Some(number(l)? as usize),
+                ("attempts", [a])               => attempts = Some(number(a)? as usize),
+                ("seed", [s])                   => seed = Some(number(s)?),
+                ("mode", ["free"])              => mode = GameMode::FreePlay,
+                ("mode", ["daily", p])          => mode = GameMode::Daily(number(p)? as u32),
+                ("provider", ["fixed"])         => adversarial = false,
+                ("provider", ["adversarial"])   => adversarial = true,
+                ("hard_mode", [h])              => hard_mode = h.parse::<bool>().map_err(|_| bad())?,
+                ("high_contrast", [h])          => high_contrast = h.parse::<bool>().map_err(|_| bad())?,
+                ("limit", [l])                  => limit = Some(Duration::from_millis(number(l)?)),
+                ("clock", [e, "running"])       => clock = (Some(Duration::from_millis(number(e)?)), false),
+                ("clock", [e, "stopped"])       => clock = (Some(Duration::from_millis(number(e)?)), true),
+                ("theme", [t])                  => theme = number(t)? as usize,
+                ("hint", [h @ .., "after", r])  => hints.push((number(r)? as usize, hint(h)?)),
+                ("guess", [g, p])               => guesses.push((g.to_string(), p.to_string())),
+                ("status", ["win", n])          => status = Some(BoardStatus::Win(number(n)? as usize)),
+                ("status", ["lose"])            => status = Some(BoardStatus::Lose),
+                ("status", ["ongoing", n])      => status = Some(BoardStatus::OnGoing(number(n)? as usize)),
+                ("status", ["not_started"])     => status = Some(BoardStatus::NotStarted),
+                _                               => return Err(bad())
+            };
+        }
+
+        match version {
+            Some(v) if v == VERSION as u64  => (),
+            Some(v)                         => return Err(format!("implBoard: Unsupported save version {}", v)),
+            None                            => return Err(String::from("implBoard: Save has no version"))
+        };
+
+        let rules = Rules::new(
+            length.ok_or(String::from("implBoard: Save has no length"))?,
+            attempts.ok_or(String::from("implBoard: Save has no attempts"))?
+        )?;
+        let answer = answer.ok_or(String::from("implBoard: Save has no answer"))?;
+
+        let mut board = Board::new(&answer, rules)?.with_dictionary(dictionary.for_length(rules.length)?);
+        board.seed = seed;
+        board.mode = mode;
+        if adversarial {
+            board.provider = AnswerProvider::adversarial(&board.dictionary.answers);
+        }
+        board.hard_mode = hard_mode;
+        board.high_contrast = high_contrast;
+        board.gui_theme_no = theme;
+        // Hints are saved in the order taken, none after the last guess
+        let rows: Vec<usize> = hints.iter().map(|(row, _)| *row).chain([guesses.len()]).collect();
+        if rows.windows(2).any(|w| w[0] > w[1]) {
+            return Err(String::from("implBoard: Saved hints are out of order"));
+        }
+        board.hints = hints;
+
+        // Revealed letters steer an adversary, so each is applied before the guess that followed it
+        let reveal_hints = |board: &mut Board, row: usize| {
+            let revealed: Vec<(usize, char)> = board.hints.iter().filter_map(|(at, hint)| match *hint {
+                Hint::Reveal { position, letter } if *at == row => Some((position, letter)),
+                _                                               => None
+            }).collect();
+            for (position, letter) in revealed {
+                board.provider.reveal(position, letter);
+            }
+        };
+
+        // Replayed without the word list or hard mode checks, they passed when first played
+        for (row, (guess, pattern)) in guesses.iter().enumerate() {
+            if !board.is_playing() || guess.chars().count() != rules.length || !guess.chars().all(|c| c.is_ascii_uppercase()) {
+                return Err(format!("implBoard: Saved guess {} doesn't fit the game", guess));
+            }
+
+            reveal_hints(&mut board, row);
+            board.guesses[row] = guess.chars().collect();
+            let feedback = board.make_correction(row)?;
+            if feedback.pattern() != *pattern {
+                return Err(format!("implBoard: Saved guess {} doesn't match its answer", guess));
+            }
+
+            board.status = match feedback.is_solved() {
+                true                                => BoardStatus::Win(row),
+                false if row + 1 == rules.attempts  => BoardStatus::Lose,
+                false                               => BoardStatus::OnGoing(row)
+            };
+        }
+        reveal_hints(&mut board, guesses.len());
+
+        if status.as_ref() != Some(&board.status) {
+            return Err(String::from("implBoard: Saved status doesn't match its guesses"));
+        }
+
+        // A running clock carries on from the saved time, not counting the time away
+        board.clock = Clock::resumed(limit, clock.0, clock.1, Instant::now());
+
+        let last = rules.attempts - 1;
+        board.gui_current_loc = [guesses.len().min(last), 0];
+        Ok(board)
+    }
+}
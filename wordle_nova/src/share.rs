@@ -0,0 +1,46 @@
+use crate::board::{Board, BoardStatus, GameMode, PrErr};
+use crate::feedback::TileState;
+
+// Share text
+//  A header and one row of squares per guess, no letters, eg.
+//      Wordle_Beta 123 3/6*
+//
+//      ⬛🟨⬛⬛🟩
+//      🟩🟩🟩🟩🟩
+//  * marks hard mode, 💡 a game where hints were used. High contrast
+//  swaps green and yellow for orange and blue.
+impl Board {
+    pub fn share(&self) -> PrErr<String> {
+        let score = match self.status {
+            BoardStatus::Win(n) => (n + 1).to_string(),
+            BoardStatus::Lose   => String::from("X"),
+            _                   => return Err(String::from("implBoard: Nothing to share until the game is over"))
+        };
+
+        let mut out = match (self.mode, self.provider.is_adversarial()) {
+            (GameMode::Daily(n), _)     => format!("Wordle_Beta {} {}/{}", n, score, self.rules.attempts),
+            (GameMode::FreePlay, true)  => format!("Wordle_Beta Absurdle {}/{}", score, self.rules.attempts),
+            (GameMode::FreePlay, false) => format!("Wordle_Beta {}/{}", score, self.rules.attempts)
+        };
+        if self.hard_mode {
+            out.push('*');
+        }
+        if !self.hints.is_empty() {
+            out += " 💡";
+        }
+        out.push('\n');
+
+        for row in self.correction.iter().filter(|row| row.is_scored()) {
+            out.push('\n');
+            out.extend(row.iter().map(|tile| match (tile.state, self.high_contrast) {
+                (TileState::Correct, false) => '🟩',
+                (TileState::Correct, true)  => '🟧',
+                (TileState::Present, false) => '🟨',
+                (TileState::Present, true)  => '🟦',
+                _                           => '⬛'
+            }));
+        }
+
+        Ok(out)
+    }
+}
@@ -0,0 +1,63 @@
+use crate::board::Board;
+use crate::feedback::{score_into, GuessFeedback, TileState, MAX_LENGTH};
+use crate::words::WordList;
+
+// Solver
+//  Keeps the words that would have produced every scored row so far.
+//  A word stays in when scoring the row's guess against it gives back
+//  the very same feedback, states and answer_count alike (the count only
+//  where it's known), so duplicate letters and letter counts are handled
+//  by the scoring rules themselves.
+pub struct Solver<'a> {
+    words: &'a WordList,
+    rows: Vec<(Vec<u8>, GuessFeedback)>
+} impl<'a> Solver<'a> {
+    pub fn new(words: &'a WordList) -> Solver<'a> {
+        Solver { words, rows: Vec::new() }
+    }
+
+    // The board's answer list, with every row the board has scored
+    pub fn from_board(board: &'a Board) -> Solver<'a> {
+        let mut solver = Solver::new(&board.dictionary.answers);
+        for (guess, feedback) in board.guesses.iter().zip(board.correction.iter()) {
+            if feedback.is_scored() {
+                solver.add(&guess.iter().collect::<String>(), feedback);
+            }
+        }
+
+        solver
+    }
+
+    pub fn add(&mut self, guess: &str, feedback: &GuessFeedback) {
+        self.rows.push((guess.to_ascii_uppercase().into_bytes(), feedback.clone()));
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_consistent(&self, word: &str) -> bool {
+        self.rows.iter().all(|(guess, feedback)| matches(word.as_bytes(), guess, feedback))
+    }
+
+    pub fn candidates(&self) -> Vec<&'a str> {
+        self.words.words().iter()
+            .map(String::as_str)
+            .filter(|w| self.is_consistent(w))
+            .collect()
+    }
+}
+
+// Would `word` as the answer have scored `guess` as `feedback`?
+fn matches(word: &[u8], guess: &[u8], feedback: &GuessFeedback) -> bool {
+    if word.len() != guess.len() || guess.len() != feedback.len() || guess.len() > MAX_LENGTH {
+        return false;
+    }
+
+    let mut states = [TileState::Pending; MAX_LENGTH];
+    score_into(guess, word, &mut states[..guess.len()]);
+
+    feedback.iter().enumerate().all(
+        |(i, tile)| tile.state == states[i] && tile.answer_count.is_none_or(|n| n as usize == word.iter().filter(|&&c| c == guess[i]).count())
+    )
+}
@@ -0,0 +1,58 @@
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::board::PrErr;
+use crate::words::WordList;
+
+// WordSource
+//  Hands out game seeds; each seed maps to exactly one answer, so a
+//  reported seed is enough to rebuild the same board. ChaCha8 keeps the
+//  mapping stable across platforms and rand releases.
+pub struct WordSource {
+    rng: ChaCha8Rng
+} impl WordSource {
+    pub fn seeded(seed: u64) -> WordSource {
+        WordSource { rng: ChaCha8Rng::seed_from_u64(seed) }
+    }
+
+    pub fn from_rng<R: RngCore>(rng: &mut R) -> WordSource {
+        WordSource::seeded(rng.next_u64())
+    }
+
+    pub fn from_entropy() -> WordSource {
+        WordSource { rng: ChaCha8Rng::from_entropy() }
+    }
+
+    pub fn next_seed(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    // Draws the next game's seed and the answer it selects
+    pub fn next_game(&mut self, answers: &WordList) -> PrErr<(u64, String)> {
+        let seed = self.next_seed();
+        Ok((seed, WordSource::pick(answers, seed)?))
+    }
+
+    // n different answers (all of them when n is larger), same seed same sample
+    pub fn sample(answers: &WordList, n: usize, seed: u64) -> Vec<String> {
+        let mut words = answers.words().to_vec();
+        words.shuffle(&mut ChaCha8Rng::seed_from_u64(seed));
+        words.truncate(n);
+
+        words
+    }
+
+    // Uniform over the whole list
+    pub fn pick(answers: &WordList, seed: u64) -> PrErr<String> {
+        if answers.is_empty() {
+            return Err(String::from("implWordSource: No answers to choose from"));
+        }
+
+        let n = ChaCha8Rng::seed_from_u64(seed).gen_range(0..answers.len());
+        match answers.get(n) {
+            Some(word)  => Ok(String::from(word)),
+            None        => Err(String::from("implWordSource: Answer index out of range"))
+        }
+    }
+}
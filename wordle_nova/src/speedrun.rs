@@ -0,0 +1,102 @@
+use std::time::{Duration, Instant};
+
+use crate::board::{Board, BoardStatus, PrErr, Rules};
+use crate::source::WordSource;
+use crate::words::Dictionary;
+
+// Speedrun
+//  A seeded list of answers solved back to back. Each board keeps its own
+//  clock; the run's time is their sum, so the pause between one word and
+//  the next isn't counted. A split is taken as each word is solved and a
+//  lost board ends the run.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Speedrun {
+    pub answers: Vec<String>,
+    pub seed: u64,
+    pub splits: Vec<Duration>, // run time when each word was solved
+    pub failed: bool,
+    dictionary: Dictionary,
+    rules: Rules
+} impl Speedrun {
+    pub fn new(dictionary: Dictionary, rules: Rules, words: usize, seed: u64) -> PrErr<Speedrun> {
+        let dictionary = dictionary.for_length(rules.length)?;
+        let answers = WordSource::sample(&dictionary.answers, words, seed);
+        if words == 0 || answers.len() < words {
+            return Err(format!("implSpeedrun: Can't pick {} words from {} answers", words, answers.len()));
+        }
+
+        Ok(Speedrun { answers, seed, splits: Vec::new(), failed: false, dictionary, rules })
+    }
+
+    // Same length of run on a fresh seed
+    pub fn again(&self) -> PrErr<Speedrun> {
+        Speedrun::new(self.dictionary.clone(), self.rules, self.answers.len(), WordSource::from_entropy().next_seed())
+    }
+
+    // Board for the word being played
+    pub fn board(&self) -> PrErr<Board> {
+        let answer = self.answers.get(self.splits.len()).ok_or(String::from("implSpeedrun: The run is over"))?;
+        Ok(Board::new(answer, self.rules)?.with_dictionary(self.dictionary.clone()))
+    }
+
+    // Word being played, counting from 1
+    pub fn word(&self) -> usize {
+        (self.splits.len() + 1).min(self.answers.len())
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.failed || self.is_complete()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.splits.len() == self.answers.len()
+    }
+
+    // Total so far, counting the board in play (already in the splits once the run is complete)
+    pub fn elapsed(&self, board: &Board, now: Instant) -> Duration {
+        let done = self.splits.last().copied().unwrap_or_default();
+        match self.is_complete() {
+            true    => done,
+            false   => done + board.clock.elapsed(now)
+        }
+    }
+
+    // Once a board is over: a win takes a split and hands out the next
+    // board (with the same settings), None when the run has ended
+    pub fn next(&mut self, finished: &Board) -> PrErr<Option<Board>> {
+        if self.is_over() {
+            return Ok(None);
+        }
+
+        match (&finished.status, finished.clock.time()) {
+            (BoardStatus::Win(_), Some(time))   => {
+                let done = self.splits.last().copied().unwrap_or_default();
+                self.splits.push(done + time);
+            },
+            (BoardStatus::Lose, _)              => {
+                self.failed = true;
+                return Ok(None);
+            },
+            _                                   => return Err(String::from("implSpeedrun: The board is still being played"))
+        };
+
+        if self.is_complete() {
+            return Ok(None);
+        }
+
+        let mut board = self.board()?;
+        board.hard_mode = finished.hard_mode;
+        board.high_contrast = finished.high_contrast;
+        board.gui_theme_no = finished.gui_theme_no;
+
+        Ok(Some(board))
+    }
+
+    // Final time of a finished run
+    pub fn time(&self) -> Option<Duration> {
+        match self.is_complete() {
+            true    => self.splits.last().copied(),
+            false   => None
+        }
+    }
+}
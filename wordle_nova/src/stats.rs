@@ -0,0 +1,176 @@
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::board::{Board, BoardStatus, PrErr};
+use crate::speedrun::Speedrun;
+use crate::store;
+
+const VERSION: u32 = 1;
+
+// Stats
+//  Lifetime results, one record per finished game. Stored as a small
+//  "key = value" text file so it can be read or fixed up by hand.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Stats {
+    pub played: u32,
+    pub wins: u32,
+    pub current_streak: u32,
+    pub max_streak: u32,
+    pub hinted: u32, // games where a hint was asked for
+    pub distribution: Vec<u32>, // distribution[n - 1] games won in n guesses
+    pub best_time: Option<Duration>, // fastest win
+    pub speedruns: Vec<(usize, Duration)> // best complete run for each number of words
+} impl Stats {
+    pub fn path() -> PrErr<PathBuf> {
+        store::data_file("stats.txt")
+    }
+
+    // Empty stats when the file doesn't exist yet
+    pub fn load(path: &PathBuf) -> PrErr<Stats> {
+        match store::read(path)? {
+            Some(text)  => Stats::parse(&text).map_err(|e| format!("{}: {}", path.display(), e)),
+            None        => Ok(Stats::default())
+        }
+    }
+
+    pub fn save(&self, path: &PathBuf) -> PrErr<()> {
+        store::write(path, &self.to_text())
+    }
+
+    // Counts a finished board; false (and no change) while it is still being played
+    pub fn record(&mut self, board: &Board) -> bool {
+        match board.status {
+            BoardStatus::Win(n) => {
+                self.wins += 1;
+                if let Some(time) = board.clock.time() {
+                    self.best_time = Some(self.best_time.map_or(time, |best| best.min(time)));
+                }
+
+                self.current_streak += 1;
+                self.max_streak = self.max_streak.max(self.current_streak);
+
+                if self.distribution.len() <= n {
+                    self.distribution.resize(n + 1, 0);
+                }
+                self.distribution[n] += 1;
+            },
+            BoardStatus::Lose   => self.current_streak = 0,
+            _                   => return false
+        };
+
+        self.played += 1;
+        if !board.hints.is_empty() {
+            self.hinted += 1;
+        }
+
+        true
+    }
+
+    // Keeps the run if it is the fastest over that many words; false unless complete
+    pub fn record_speedrun(&mut self, run: &Speedrun) -> bool {
+        let time = match run.time() {
+            Some(t) => t,
+            None    => return false
+        };
+
+        match self.speedruns.iter_mut().find(|(words, _)| *words == run.answers.len()) {
+            Some((_, best)) => *best = (*best).min(time),
+            None            => {
+                self.speedruns.push((run.answers.len(), time));
+                self.speedruns.sort();
+            }
+        };
+
+        true
+    }
+
+    pub fn best_speedrun(&self, words: usize) -> Option<Duration> {
+        self.speedruns.iter().find(|(n, _)| *n == words).map(|(_, time)| *time)
+    }
+
+    // Rounded to the nearest whole percent
+    pub fn win_percent(&self) -> u32 {
+        match self.played {
+            0   => 0,
+            n   => (self.wins * 100 + n / 2) / n
+        }
+    }
+
+    pub fn parse(text: &str) -> PrErr<Stats> {
+        let mut stats = Stats::default();
+        let mut version = None;
+
+        for (n, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line.split_once('=')
+                .map(|(k, v)| (k.trim(), v.trim()))
+                .ok_or(format!("implStats: Line {} is not key = value", n + 1))?;
+            let number = |value: &str| value.parse::<u32>().map_err(|e| format!("implStats: Bad {} {}: {}", key, value, e));
+            let millis = |value: &str| value.parse::<u64>().map(Duration::from_millis).map_err(|e| format!("implStats: Bad {} {}: {}", key, value, e));
+
+            match key {
+                "version"           => version = Some(number(value)?),
+                "played"            => stats.played = number(value)?,
+                "wins"              => stats.wins = number(value)?,
+                "current_streak"    => stats.current_streak = number(value)?,
+                "max_streak"        => stats.max_streak = number(value)?,
+                "hinted"            => stats.hinted = number(value)?,
+                "distribution"      => stats.distribution = value.split_whitespace().map(number).collect::<PrErr<Vec<u32>>>()?,
+                "best_time"         => stats.best_time = Some(millis(value)?),
+                "speedrun"          => match value.split_once(' ') {
+                    Some((words, time)) => stats.speedruns.push((number(words)? as usize, millis(time.trim())?)),
+                    None                => return Err(format!("implStats: Line {} is not speedrun = words time", n + 1))
+                },
+                _                   => () // written by a newer version
+            };
+        }
+
+        match version {
+            Some(VERSION)   => Ok(stats),
+            Some(v)         => Err(format!("implStats: Unsupported version {}", v)),
+            None            => Err(String::from("implStats: No version"))
+        }
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "version = {}", VERSION);
+        let _ = writeln!(out, "played = {}", self.played);
+        let _ = writeln!(out, "wins = {}", self.wins);
+        let _ = writeln!(out, "current_streak = {}", self.current_streak);
+        let _ = writeln!(out, "max_streak = {}", self.max_streak);
+        let _ = writeln!(out, "hinted = {}", self.hinted);
+        let _ = writeln!(out, "distribution = {}", self.distribution.iter().map(u32::to_string).collect::<Vec<String>>().join(" "));
+        if let Some(time) = self.best_time {
+            let _ = writeln!(out, "best_time = {}", time.as_millis());
+        }
+        for (words, time) in &self.speedruns {
+            let _ = writeln!(out, "speedrun = {} {}", words, time.as_millis());
+        }
+
+        out
+    }
+
+    // Games won in n guesses, counting from 1
+    pub fn won_in(&self, n: usize) -> u32 {
+        n.checked_sub(1).and_then(|i| self.distribution.get(i)).copied().unwrap_or(0)
+    }
+} impl Default for Stats {
+    fn default() -> Stats {
+        Stats {
+            played: 0,
+            wins: 0,
+            current_streak: 0,
+            max_streak: 0,
+            hinted: 0,
+            distribution: vec![0; 6],
+            best_time: None,
+            speedruns: Vec::new()
+        }
+    }
+}
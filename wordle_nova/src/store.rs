@@ -0,0 +1,43 @@
+use std::path::PathBuf;
+
+use crate::board::PrErr;
+
+// Local data files (stats, saved games)
+//  $WORDLE_NOVA_DATA if set, otherwise the platform's per-user data
+//  directory: $XDG_DATA_HOME, ~/.local/share or %APPDATA%.
+pub(crate) fn data_file(name: &str) -> PrErr<PathBuf> {
+    let var = |key: &str| std::env::var_os(key).filter(|v| !v.is_empty()).map(PathBuf::from);
+
+    let dir = match var("WORDLE_NOVA_DATA") {
+        Some(dir)   => dir,
+        None        => var("XDG_DATA_HOME")
+            .or_else(|| var("HOME").map(|home| home.join(".local").join("share")))
+            .or_else(|| var("APPDATA"))
+            .ok_or(String::from("store: No data directory, set WORDLE_NOVA_DATA"))?
+            .join("wordle_nova")
+    };
+
+    Ok(dir.join(name))
+}
+
+// Written beside the target then renamed over it, so a crash mid-write
+// never leaves half a file behind
+pub(crate) fn write(path: &PathBuf, text: &str) -> PrErr<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| format!("store: Cannot create {}: {}", dir.display(), e))?;
+    }
+
+    let partial = path.with_extension("partial");
+    std::fs::write(&partial, text)
+        .and_then(|_| std::fs::rename(&partial, path))
+        .map_err(|e| format!("store: Cannot write {}: {}", path.display(), e))
+}
+
+// None when there is no file yet
+pub(crate) fn read(path: &PathBuf) -> PrErr<Option<String>> {
+    match std::fs::read_to_string(path) {
+        Ok(text)                                            => Ok(Some(text)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound  => Ok(None),
+        Err(e)                                              => Err(format!("store: Cannot read {}: {}", path.display(), e))
+    }
+}
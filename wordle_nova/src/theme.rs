@@ -0,0 +1,28 @@
+use crate::feedback::TileState;
+
+// Themes
+//  The front-ends' palettes as 24-bit RGB, one row per theme:
+//  [N/A, Wrong, Right but diff place, Right, Selected Border, BG]
+pub type Rgb = (u8, u8, u8);
+pub const THEMES: [[Rgb; 6]; 4] = [
+    [(54, 52, 50), (239, 96, 36), (240, 148, 31), (25, 103, 116), (0x50, 0x50, 0x50), (255, 255, 255)],
+    [(0, 88, 91), (36, 80, 112), (143, 223, 136), (45, 166, 108), (181, 232, 174), (239, 247, 233)],
+    [(18, 18, 19), (58, 58, 60), (181, 159, 59), (83, 141, 78), (129, 131, 132), (18, 18, 19)],
+    [(216, 180, 149), (67, 64, 89), (191, 128, 105), (165, 104, 115), (242, 226, 196), (242, 226, 196)]
+];
+
+// Colour-blind palette, the same in every theme
+pub const CONTRAST_PRESENT: Rgb = (133, 192, 249);
+pub const CONTRAST_CORRECT: Rgb = (245, 121, 58);
+
+pub fn tile_colour(theme: usize, high_contrast: bool, state: TileState) -> Rgb {
+    match (state, high_contrast) {
+        (TileState::Present, true)  => CONTRAST_PRESENT,
+        (TileState::Correct, true)  => CONTRAST_CORRECT,
+        (TileState::Pending, _)     => THEMES[theme][0],
+        (TileState::Absent, _)      => THEMES[theme][1],
+        (TileState::Present, _)     => THEMES[theme][2],
+        (TileState::Correct, _)     => THEMES[theme][3],
+        (TileState::Error, _)       => THEMES[theme][4]
+    }
+}
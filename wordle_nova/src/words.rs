@@ -0,0 +1,152 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::{Arc, OnceLock};
+
+use crate::board::PrErr;
+
+// The bundled dictionary, compiled in so no file is needed at runtime
+const DICTIONARY: &str = include_str!("../dictionary.txt");
+
+// WordList
+//  Upper-cased words in file order, plus an index for membership checks.
+#[derive(PartialEq, Eq)]
+pub struct WordList {
+    words: Vec<String>,
+    index: HashSet<String>
+} impl WordList {
+    pub fn parse(text: &str) -> PrErr<WordList> {
+        let mut words = Vec::new();
+        for (n, line) in text.lines().enumerate() {
+            let word = line.trim();
+            if word.is_empty() {
+                continue;
+            }
+            if !word.chars().all(|c| c.is_ascii_alphabetic()) {
+                return Err(format!("implWordList: Line {} is not a word: {:?}", n + 1, word));
+            }
+            words.push(word.to_ascii_uppercase());
+        }
+
+        if words.is_empty() {
+            return Err(String::from("implWordList: No words in list"));
+        }
+
+        Ok(WordList::from_words(words))
+    }
+
+    fn from_words(words: Vec<String>) -> WordList {
+        let index = words.iter().cloned().collect();
+        WordList { words, index }
+    }
+
+    // Only the words of the given length, in the same order
+    pub fn of_length(&self, length: usize) -> WordList {
+        WordList::from_words(self.words.iter().filter(|w| w.len() == length).cloned().collect())
+    }
+
+    // This list followed by any words of `other` it's missing
+    pub fn merged(&self, other: &WordList) -> WordList {
+        let missing = other.words.iter().filter(|w| !self.contains(w));
+        WordList::from_words(self.words.iter().chain(missing).cloned().collect())
+    }
+
+    pub fn all_of_length(&self, length: usize) -> bool {
+        self.words.iter().all(|w| w.len() == length)
+    }
+
+    pub fn load(path: &str) -> PrErr<WordList> {
+        match std::fs::read_to_string(path) {
+            Ok(text)    => WordList::parse(&text).map_err(|e| format!("{}: {}", path, e)),
+            Err(e)      => Err(format!("implWordList: Cannot read {}: {}", path, e))
+        }
+    }
+
+    pub fn bundled() -> Arc<WordList> {
+        static BUNDLED: OnceLock<Arc<WordList>> = OnceLock::new();
+        BUNDLED.get_or_init(
+            || Arc::new(WordList::parse(DICTIONARY).expect("bundled dictionary.txt is a valid word list"))
+        ).clone()
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.index.contains(word)
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn get(&self, n: usize) -> Option<&str> {
+        self.words.get(n).map(String::as_str)
+    }
+
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+}
+
+// Dictionary
+//  Answers are drawn from one list, guesses checked against another.
+//  Both are the bundled list unless overridden explicitly. Once cut to
+//  a length the answers are always accepted as guesses too.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Dictionary {
+    pub answers: Arc<WordList>,
+    pub accepted: Arc<WordList>
+} impl Dictionary {
+    pub fn bundled() -> Dictionary {
+        Dictionary {
+            answers: WordList::bundled(),
+            accepted: WordList::bundled()
+        }
+    }
+
+    pub fn with_answers_file(mut self, path: &str) -> PrErr<Dictionary> {
+        self.answers = Arc::new(WordList::load(path)?);
+        Ok(self)
+    }
+
+    pub fn with_accepted_file(mut self, path: &str) -> PrErr<Dictionary> {
+        self.accepted = Arc::new(WordList::load(path)?);
+        Ok(self)
+    }
+
+    // Both lists cut down to one word length; untouched when they already fit
+    pub fn for_length(self, length: usize) -> PrErr<Dictionary> {
+        fn cut(list: Arc<WordList>, length: usize) -> Arc<WordList> {
+            match list.all_of_length(length) {
+                true    => list,
+                false   => Arc::new(list.of_length(length))
+            }
+        }
+
+        let answers = cut(self.answers, length);
+        let accepted = cut(self.accepted, length);
+        let accepted = match Arc::ptr_eq(&answers, &accepted) || answers.words.iter().all(|w| accepted.contains(w)) {
+            true    => accepted,
+            false   => Arc::new(accepted.merged(&answers))
+        };
+        let dictionary = Dictionary { answers, accepted };
+
+        match dictionary.answers.is_empty() {
+            true    => Err(format!("implDictionary: No {} letter answers in the word list", length)),
+            false   => Ok(dictionary)
+        }
+    }
+}
+
+impl Default for Dictionary {
+    fn default() -> Dictionary {
+        Dictionary::bundled()
+    }
+}
+
+impl fmt::Debug for WordList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "WordList({} words)", self.words.len())
+    }
+}
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use wordle_nova::{Board, BoardStatus, Dictionary, GuessFeedback, LaunchOptions, Rules, Solver};
+
+fn absurdle(attempts: usize) -> Board {
+    Board::adversarial(Dictionary::bundled(), Rules::new(5, attempts).unwrap()).unwrap()
+}
+
+#[test]
+fn keeps_the_largest_group() {
+    let mut board = absurdle(6);
+    let feedback = board.enter_word("SLATE").unwrap();
+
+    let mut groups: HashMap<Vec<(char, Option<u8>)>, usize> = HashMap::new();
+    for word in Dictionary::bundled().answers.words() {
+        let answer: Vec<char> = word.chars().collect();
+        let score = GuessFeedback::score(&['S', 'L', 'A', 'T', 'E'], &answer).unwrap();
+        *groups.entry(score.pattern().chars().zip(score.iter().map(|t| t.answer_count)).collect()).or_default() += 1;
+    }
+
+    let remaining = board.provider.remaining().unwrap();
+    assert_eq!(remaining, *groups.values().max().unwrap());
+    assert_eq!(Solver::from_board(&board).candidates().len(), remaining);
+    assert!(!feedback.is_solved());
+}
+
+#[test]
+fn can_still_be_beaten() {
+    let mut board = absurdle(Rules::MAX_ATTEMPTS);
+    while board.is_playing() {
+        let guess = Solver::from_board(&board).candidates()[0].to_string();
+        board.enter_word(&guess).unwrap();
+    }
+
+    assert!(matches!(board.status, BoardStatus::Win(_)));
+    assert_eq!(board.provider.remaining(), Some(1));
+    assert!(board.share().unwrap().starts_with("Wordle_Beta Absurdle "));
+}
+
+#[test]
+fn saved_game_replays_the_same_choices() {
+    let mut board = absurdle(6);
+    board.enter_word("CRANE").unwrap();
+    board.enter_word("MOIST").unwrap();
+
+    let resumed = Board::from_save(&board.to_save(), Dictionary::bundled()).unwrap();
+    assert_eq!(resumed, board);
+
+    let mut fresh = board.clone();
+    fresh.reset().unwrap();
+    assert!(fresh.provider.is_adversarial());
+    assert_eq!(fresh.status, BoardStatus::NotStarted);
+}
+
+#[test]
+fn saved_game_replays_hints_where_they_were_taken() {
+    let mut board = absurdle(6);
+    board.enter_word("SLATE").unwrap();
+    for _ in 0..3 {
+        board.hint().unwrap();
+    }
+    board.enter_word("CRONY").unwrap();
+
+    let save = board.to_save();
+    assert!(save.contains(" after 1"));
+    assert_eq!(Board::from_save(&save, Dictionary::bundled()), Ok(board));
+}
+
+#[test]
+fn launch_flag_conflicts() {
+    let args = |list: &[&str]| LaunchOptions::from_args(list.iter().map(|s| s.to_string()));
+
+    assert!(args(&["--adversarial"]).unwrap().board().unwrap().provider.is_adversarial());
+    assert!(args(&["--adversarial", "--daily"]).is_err());
+    assert!(args(&["--adversarial", "--seed", "4"]).is_err());
+}
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use wordle_nova::{Board, Challenge, Dictionary, LaunchOptions, Rules, WordList};
+
+#[test]
+fn codes_round_trip() {
+    let words = Arc::new(WordList::parse("WORD\nCRANE\nZEPHYR\nJUKEBOX\nQUIZZERS").unwrap());
+    let dictionary = Dictionary { answers: words.clone(), accepted: words.clone() };
+
+    for (length, word) in Rules::LENGTHS.zip(words.words()) {
+        let rules = Rules::new(length, length + 1).unwrap();
+        for hard_mode in [false, true] {
+            let challenge = Challenge::new(&dictionary, &word.to_lowercase(), rules, hard_mode).unwrap();
+            assert_eq!(&challenge.answer, word);
+            assert_eq!(Challenge::decode(&challenge.code()), Ok(challenge));
+        }
+    }
+}
+
+#[test]
+fn codes_hide_the_word() {
+    let challenge = Challenge::new(&Dictionary::bundled(), "CRANE", Rules::default(), false).unwrap();
+    let codes: Vec<String> = (0..8).map(|_| challenge.code()).collect();
+
+    assert!(codes.iter().all(|code| !code.contains("CRANE") && code.len() <= 13));
+    assert!(codes.iter().any(|code| *code != codes[0]));
+}
+
+#[test]
+fn codes_are_read_leniently() {
+    let challenge = Challenge::new(&Dictionary::bundled(), "CRANE", Rules::default(), true).unwrap();
+    let code = challenge.code();
+    let typed = format!(" {}-{} ", code[..6].to_lowercase(), &code[6..]).replace('0', "o").replace('1', "l");
+
+    assert_eq!(Challenge::decode(&typed), Ok(challenge));
+}
+
+#[test]
+fn bad_codes_are_rejected() {
+    let challenge = Challenge::new(&Dictionary::bundled(), "CRANE", Rules::default(), false).unwrap();
+    let code = challenge.code_with(7);
+    assert_eq!(code, "0YRZAJKP6KAGA");
+
+    // A letter of the word, and the last symbol with its padding bit
+    for (n, typo) in [(5, 'M'), (12, 'B')] {
+        let mut garbled: Vec<char> = code.chars().collect();
+        garbled[n] = typo;
+        assert!(Challenge::decode(&garbled.into_iter().collect::<String>()).is_err());
+    }
+
+    assert!(Challenge::decode(&format!("{}0", code)).is_err());
+    assert!(Challenge::decode(&code[..code.len() - 2]).is_err());
+    assert!(Challenge::decode("").is_err());
+    assert!(Challenge::decode("CRANE!").is_err());
+}
+
+#[test]
+fn only_dictionary_words() {
+    let dictionary = Dictionary::bundled();
+    assert!(Challenge::new(&dictionary, "XQZVW", Rules::default(), false).is_err());
+    assert!(Challenge::new(&dictionary, "CRANES", Rules::default(), false).is_err());
+}
+
+#[test]
+fn launch_plays_the_challenge() {
+    let rules = Rules::new(5, 4).unwrap();
+    let code = Challenge::new(&Dictionary::bundled(), "CRANE", rules, true).unwrap().code();
+    let args = |list: &[&str]| LaunchOptions::from_args(list.iter().map(|s| s.to_string()));
+
+    let options = args(&["--challenge", &code]).unwrap();
+    assert_eq!(options.rules, rules);
+
+    let board = options.board().unwrap();
+    assert_eq!(board.answer, "CRANE");
+    assert_eq!(board.seed, None);
+    assert!(board.hard_mode);
+
+    // A different saved game gives way to the challenge, the same one is resumed
+    let mut other = Board::new("SLATE", rules).unwrap();
+    other.enter_word("CRANE").unwrap();
+    assert_eq!(args(&["--challenge", &code]).unwrap().resume(Some(other)).unwrap().answer, "CRANE");
+
+    let mut started = args(&["--challenge", &code]).unwrap().board().unwrap();
+    started.enter_word("SLATE").unwrap();
+    assert_eq!(args(&["--challenge", &code]).unwrap().resume(Some(started.clone())), Ok(started));
+
+    assert!(args(&["--challenge", &code, "--daily"]).is_err());
+    assert!(args(&["--challenge", "NOTACODE"]).is_err());
+}
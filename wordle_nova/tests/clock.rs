@@ -0,0 +1,102 @@
+use std::time::{Duration, Instant};
+
+mod common;
+
+use common::played;
+use wordle_nova::{Board, BoardStatus, Clock, Dictionary, GuessError, LaunchOptions, Rules, Speedrun, Stats, Stopwatch};
+
+fn blitz(seconds: u64) -> Board {
+    let mut board = played("CRANE", &[]);
+    board.clock = Clock::countdown(Duration::from_secs(seconds));
+    board
+}
+
+#[test]
+fn countdown_loses_the_game() {
+    let mut board = blitz(60);
+    assert!(!board.check_time(Instant::now() + Duration::from_secs(600)));
+
+    board.push_letter('S');
+    assert!(board.clock.is_started());
+    assert!(!board.check_time(Instant::now() + Duration::from_secs(59)));
+    assert!(board.check_time(Instant::now() + Duration::from_secs(61)));
+    assert_eq!(board.status, BoardStatus::Lose);
+    assert_eq!(board.clock.time(), Some(Duration::from_secs(60)));
+    assert_eq!(board.enter_word("CRANE"), Err(GuessError::GameOver));
+}
+
+#[test]
+fn late_guess_is_out_of_time() {
+    let mut board = blitz(60);
+    board.clock = Clock::resumed(board.clock.limit, Some(Duration::from_secs(61)), false, Instant::now());
+
+    assert_eq!(board.enter_word("CRANE"), Err(GuessError::OutOfTime));
+    assert_eq!(board.status, BoardStatus::Lose);
+}
+
+#[test]
+fn wins_stop_the_clock_and_set_best_time() {
+    let mut board = blitz(60);
+    board.enter_word("CRANE").unwrap();
+
+    let time = board.clock.time().unwrap();
+    assert!(time < Duration::from_secs(60));
+    assert_eq!(board.clock.elapsed(Instant::now() + Duration::from_secs(100)), time);
+
+    let mut stats = Stats::default();
+    stats.record(&board);
+    assert_eq!(stats.best_time, Some(time));
+
+    assert_eq!(Stopwatch(Duration::from_millis(67_340)).to_string(), "1:07.3");
+}
+
+#[test]
+fn speedrun_takes_splits_until_done() {
+    let mut run = Speedrun::new(Dictionary::bundled(), Rules::default(), 3, 9).unwrap();
+    assert_eq!(run, Speedrun::new(Dictionary::bundled(), Rules::default(), 3, 9).unwrap());
+
+    let mut board = run.board().unwrap();
+    board.hard_mode = true;
+    for word in 1..=3 {
+        assert_eq!(run.word(), word);
+        let answer = board.answer.clone();
+        board.enter_word(&answer).unwrap();
+
+        match run.next(&board).unwrap() {
+            Some(next)  => { assert!(next.hard_mode); board = next; },
+            None        => assert_eq!(word, 3)
+        };
+    }
+
+    assert!(run.is_complete());
+    assert_eq!(run.splits.len(), 3);
+    assert!(run.splits.windows(2).all(|w| w[0] <= w[1]));
+
+    let mut stats = Stats::default();
+    assert!(stats.record_speedrun(&run));
+    assert_eq!(stats.best_speedrun(3), run.time());
+    assert_eq!(Stats::parse(&stats.to_text()), Ok(stats));
+}
+
+#[test]
+fn lost_board_ends_the_run() {
+    let mut run = Speedrun::new(Dictionary::bundled(), Rules::new(5, 1).unwrap(), 5, 1).unwrap();
+    let mut board = run.board().unwrap();
+    let wrong = if board.answer == "CRANE" { "SLATE" } else { "CRANE" };
+    board.enter_word(wrong).unwrap();
+
+    assert_eq!(run.next(&board), Ok(None));
+    assert!(run.failed && run.is_over());
+    assert!(!Stats::default().record_speedrun(&run));
+}
+
+#[test]
+fn launch_options() {
+    let args = |list: &[&str]| LaunchOptions::from_args(list.iter().map(|s| s.to_string()));
+
+    let board = args(&["--blitz", "90"]).unwrap().board().unwrap();
+    assert_eq!(board.clock.limit, Some(Duration::from_secs(90)));
+    assert_eq!(args(&["--speedrun", "5", "--seed", "2"]).unwrap().speedrun().unwrap().answers.len(), 5);
+    assert!(args(&["--speedrun", "5", "--boards", "4"]).is_err());
+    assert!(args(&["--speedrun", "5"]).unwrap().board().is_err());
+}
@@ -0,0 +1,23 @@
+// Fixtures shared by the integration tests; each file uses only some
+#![allow(dead_code)]
+
+use wordle_nova::{Board, MultiBoard, Rules};
+
+// Enters each guess in turn; every one must be accepted
+pub fn play(board: &mut Board, guesses: &[&str]) {
+    for guess in guesses {
+        board.enter_word(guess).unwrap();
+    }
+}
+
+// A game under the default rules with these guesses made
+pub fn played(answer: &str, guesses: &[&str]) -> Board {
+    let mut board = Board::new(answer, Rules::default()).unwrap();
+    play(&mut board, guesses);
+    board
+}
+
+// Four boards, with the attempts four get
+pub fn quordle() -> MultiBoard {
+    MultiBoard::new(&["CRANE", "SLATE", "TRACE", "BLOKE"], Rules::new(5, MultiBoard::attempts_for(4)).unwrap()).unwrap()
+}
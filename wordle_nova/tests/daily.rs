@@ -0,0 +1,38 @@
+use wordle_nova::{Board, Daily, Date, Dictionary, GameMode, Rules};
+
+#[test]
+fn dates_round_trip_through_day_numbers() {
+    for (text, days) in [("1970-01-01", 0), ("2000-02-29", 11_016), ("2021-06-19", 18_797), ("1969-12-31", -1)] {
+        let date = Date::parse(text).unwrap();
+        assert_eq!(date.days(), days, "{}", text);
+        assert_eq!(Date::from_days(days), date);
+        assert_eq!(date.to_string(), text);
+    }
+
+    assert!(Date::parse("2023-02-29").is_err());
+    assert!(Date::parse("2023-13-01").is_err());
+}
+
+#[test]
+fn same_date_gives_same_puzzle() {
+    let daily = Daily::default();
+    let date = Date::parse("2024-03-01").unwrap();
+
+    let a = Board::daily(Dictionary::bundled(), Rules::default(), &daily, date).unwrap();
+    let b = Board::daily(Dictionary::bundled(), Rules::default(), &daily, date).unwrap();
+    assert_eq!(a.answer, b.answer);
+    assert_eq!(a.mode, GameMode::Daily(986));
+
+    let next = Board::daily(Dictionary::bundled(), Rules::default(), &daily, Date::from_days(date.days() + 1)).unwrap();
+    assert_eq!(next.mode, GameMode::Daily(987));
+    assert_ne!(a.answer, next.answer);
+}
+
+#[test]
+fn epoch_moves_the_puzzle_number() {
+    let daily = Daily::new(Date::parse("2024-01-01").unwrap());
+
+    assert_eq!(daily.puzzle_number(Date::parse("2024-01-01").unwrap()), Ok(0));
+    assert_eq!(daily.puzzle_number(Date::parse("2024-02-01").unwrap()), Ok(31));
+    assert!(daily.puzzle_number(Date::parse("2023-12-31").unwrap()).is_err());
+}
@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use wordle_nova::{Board, BoardStatus, Dictionary, GuessError, Rules, TileState, WordList, WordSource};
+
+fn type_word(board: &mut Board, word: &str) {
+    for c in word.chars() {
+        board.push_letter(c);
+    }
+}
+
+#[test]
+fn unknown_word_is_rejected_without_using_a_row() {
+    let mut board = Board::with_answer("CRANE").unwrap();
+    type_word(&mut board, "QQQQQ");
+
+    assert_eq!(board.submit_row(), Err(GuessError::NotInWordList(String::from("QQQQQ"))));
+    assert_eq!(board.status, BoardStatus::NotStarted);
+    assert_eq!(board.gui_current_loc, [0, 5]);
+
+    for _ in 0..5 {
+        board.pop_letter();
+    }
+    type_word(&mut board, "SLATE");
+    assert!(board.submit_row().is_ok());
+    assert_eq!(board.status, BoardStatus::OnGoing(0));
+    assert_eq!(board.gui_current_loc, [1, 0]);
+}
+
+#[test]
+fn short_row_is_rejected() {
+    let mut board = Board::with_answer("CRANE").unwrap();
+    type_word(&mut board, "SLA");
+
+    assert_eq!(board.submit_row(), Err(GuessError::NotEnoughLetters));
+    assert_eq!(board.status, BoardStatus::NotStarted);
+}
+
+#[test]
+fn answer_list_may_differ_from_accepted_list() {
+    let accepted = Arc::new(WordList::parse("slate\ncrane\n").unwrap());
+    let mut board = Board::with_answer("ZESTY").unwrap().with_accepted(accepted);
+
+    type_word(&mut board, "ABIDE");
+    assert!(matches!(board.submit_row(), Err(GuessError::NotInWordList(_))));
+
+    for _ in 0..5 {
+        board.pop_letter();
+    }
+    type_word(&mut board, "ZESTY");
+    assert!(board.submit_row().unwrap().is_solved());
+    assert_eq!(board.status, BoardStatus::Win(0));
+}
+
+#[test]
+fn hard_mode_keeps_revealed_hints() {
+    let mut board = Board::with_answer("CRANE").unwrap();
+    board.set_hard_mode(true).unwrap();
+
+    // C and R are green, E is yellow
+    type_word(&mut board, "CREPT");
+    board.submit_row().unwrap();
+    assert!(board.set_hard_mode(false).is_err());
+
+    type_word(&mut board, "CLEAN");
+    let err = board.submit_row().unwrap_err();
+    assert_eq!(err, GuessError::MissingCorrect { position: 1, letter: 'R' });
+    assert_eq!(err.to_string(), "2nd letter must be R");
+    assert_eq!(board.status, BoardStatus::OnGoing(0));
+
+    for _ in 0..5 {
+        board.pop_letter();
+    }
+    type_word(&mut board, "CROWD");
+    let err = board.submit_row().unwrap_err();
+    assert_eq!(err, GuessError::MissingPresent { letter: 'E', count: 1 });
+    assert_eq!(err.to_string(), "Guess must contain E");
+
+    for _ in 0..5 {
+        board.pop_letter();
+    }
+    type_word(&mut board, "CRANE");
+    assert!(board.submit_row().unwrap().is_solved());
+}
+
+#[test]
+fn rules_set_length_and_attempts() {
+    let dictionary = Dictionary { answers: Arc::new(WordList::parse("cat\nlemon\nbread\nplanet").unwrap()), ..Dictionary::bundled() };
+    let mut board = Board::seeded(dictionary, Rules::new(6, 2).unwrap(), 1).unwrap();
+    assert_eq!(board.answer, "PLANET");
+    assert_eq!(board.guesses.len(), 2);
+    assert_eq!(board.guesses[0].len(), 6);
+
+    // The bundled list has no 6 letter words, but the answers are accepted
+    type_word(&mut board, "PLANES");
+    assert!(matches!(board.submit_row(), Err(GuessError::NotInWordList(_))));
+
+    let answers = Arc::new(WordList::parse("silver\norange").unwrap());
+    let mut board = Board::seeded(Dictionary { answers, ..Dictionary::bundled() }, Rules::new(6, 2).unwrap(), 1).unwrap();
+    let other = if board.answer == "SILVER" { "ORANGE" } else { "SILVER" };
+    type_word(&mut board, other);
+    assert!(!board.submit_row().unwrap().is_solved());
+    assert_eq!(board.status, BoardStatus::OnGoing(0));
+
+    let mut board = Board::new("PLANET", Rules::new(6, 1).unwrap())
+        .unwrap()
+        .with_accepted(Arc::new(WordList::parse("planes").unwrap()));
+    type_word(&mut board, "PLANESX");
+    assert_eq!(board.gui_current_loc, [0, 6]);
+    board.submit_row().unwrap();
+    assert_eq!(board.status, BoardStatus::Lose);
+
+    assert!(Rules::new(3, 6).is_err());
+    assert!(Rules::new(9, 6).is_err());
+    assert!(Rules::new(5, Rules::MAX_ATTEMPTS).is_ok());
+    assert!(Rules::new(5, 100_000_000).is_err());
+
+    // Rules written out by hand get the same checks
+    assert!(Board::new("CRANE", Rules { length: 5, attempts: 0 }).is_err());
+    assert!(Board::new("CAT", Rules { length: 3, attempts: 6 }).is_err());
+    assert!(Board::new("AB1DE", Rules::default()).is_err());
+    assert!(Board::with_answer("CAT").is_err());
+}
+
+#[test]
+fn reset_starts_over_with_the_same_settings() {
+    let mut board = Board::seeded(Dictionary::bundled(), Rules::default(), 11).unwrap();
+    board.set_hard_mode(true).unwrap();
+    board.gui_theme_no = 2;
+
+    let answer = board.answer.clone();
+    type_word(&mut board, &answer);
+    board.submit_row().unwrap();
+    assert_eq!(board.status, BoardStatus::Win(0));
+
+    board.reset_from(&mut WordSource::seeded(5)).unwrap();
+    assert_eq!(board.status, BoardStatus::NotStarted);
+    assert_eq!(board.gui_current_loc, [0, 0]);
+    assert!(board.guesses.iter().flatten().all(|&c| c == '·'));
+    assert!(board.correction.iter().all(|row| !row.is_scored()));
+    assert!(board.gui_letter_hint.iter().all(|&h| h == TileState::Pending));
+    assert!(board.hard_mode);
+    assert_eq!(board.gui_theme_no, 2);
+    assert_ne!(board.seed, Some(11));
+}
@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+
+mod common;
+
+use common::quordle;
+use wordle_nova::{BoardStatus, Dictionary, GuessError, LaunchOptions, MultiBoard, Rules, TileState};
+
+#[test]
+fn attempts_scale_with_boards() {
+    let attempts: Vec<usize> = MultiBoard::COUNTS.iter().map(|&n| MultiBoard::attempts_for(n)).collect();
+    assert_eq!(attempts, [7, 9, 13]);
+
+    let options = LaunchOptions::from_args(["--boards", "4"].iter().map(|s| s.to_string())).unwrap();
+    assert_eq!((options.boards, options.rules.attempts), (4, 9));
+    let options = LaunchOptions::from_args(["--boards", "2", "--attempts", "10"].iter().map(|s| s.to_string())).unwrap();
+    assert_eq!(options.rules.attempts, 10);
+    assert!(LaunchOptions::from_args(["--boards", "3"].iter().map(|s| s.to_string())).is_err());
+}
+
+#[test]
+fn seeded_boards_have_different_answers() {
+    let a = MultiBoard::seeded(Dictionary::bundled(), Rules::default(), 8, 5).unwrap();
+    let b = MultiBoard::seeded(Dictionary::bundled(), Rules::default(), 8, 5).unwrap();
+
+    let answers: HashSet<&str> = a.boards.iter().map(|b| b.answer.as_str()).collect();
+    assert_eq!(answers.len(), 8);
+    assert_eq!(a, b);
+}
+
+#[test]
+fn solved_boards_stop_taking_guesses() {
+    let mut game = quordle();
+
+    let feedback = game.enter_word("CRANE").unwrap();
+    assert!(feedback[0].as_ref().unwrap().is_solved());
+    assert_eq!(feedback[1].as_ref().unwrap().pattern(), "..G.G");
+    assert_eq!(game.status(), BoardStatus::OnGoing(0));
+
+    let feedback = game.enter_word("SLATE").unwrap();
+    assert_eq!(feedback[0], None);
+    assert_eq!(game.boards[0].status, BoardStatus::Win(0));
+    assert_eq!(game.boards[1].status, BoardStatus::Win(1));
+
+    game.enter_word("TRACE").unwrap();
+    game.enter_word("BLOKE").unwrap();
+    assert_eq!(game.status(), BoardStatus::Win(3));
+    assert_eq!(game.enter_word("CRANE"), Err(GuessError::GameOver));
+    assert!(game.share().unwrap().starts_with("Wordle_Beta Quordle 1 2 3 4/9\n\n🟩🟩🟩🟩🟩\n"));
+}
+
+#[test]
+fn rejected_guess_lands_on_no_board() {
+    let mut game = quordle();
+    game.boards[2].hard_mode = true;
+    game.enter_word("CRANE").unwrap();
+
+    // Fine on every board but the hard mode one, which needs its R and A kept
+    let before = game.clone();
+    assert!(matches!(game.enter_word("SLOTH"), Err(GuessError::MissingCorrect { .. }) | Err(GuessError::MissingPresent { .. })));
+    assert_eq!(game, before);
+
+    assert!(game.enter_word("QQQQQ").is_err());
+    assert_eq!(game, before);
+}
+
+#[test]
+fn one_unsolved_board_loses_the_game() {
+    let mut game = MultiBoard::new(&["CRANE", "SLATE"], Rules::new(5, 2).unwrap()).unwrap();
+    game.enter_word("CRANE").unwrap();
+    game.enter_word("TRACE").unwrap();
+
+    assert_eq!(game.status(), BoardStatus::Lose);
+    assert!(!game.is_playing());
+}
+
+#[test]
+fn keyboard_hints_are_kept_per_board() {
+    let mut game = quordle();
+    game.push_letter('c');
+    game.push_letter('r');
+    assert_eq!(game.current_loc(), Some([0, 2]));
+
+    game.clear_row();
+    game.enter_word("CRANE").unwrap();
+    assert_eq!(game.letter_hints('C'), [TileState::Correct, TileState::Absent, TileState::Present, TileState::Absent]);
+    assert_eq!(game.letter_hints('1'), [TileState::Pending; 4]);
+}
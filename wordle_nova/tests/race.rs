@@ -0,0 +1,122 @@
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+use wordle_nova::{Dictionary, GuessFeedback, LaunchOptions, PrErr, Race, RaceClient, RaceHost, Rules, ToHost, ToPlayer};
+
+fn host(players: usize, rules: Rules) -> (String, thread::JoinHandle<PrErr<Option<String>>>) {
+    let host = RaceHost::bind("127.0.0.1:0", players, 42, rules).unwrap();
+    let address = host.local_addr().unwrap().to_string();
+    (address, thread::spawn(move || host.run()))
+}
+
+fn join(address: &str, name: &str) -> (RaceClient, Race, Receiver<PrErr<ToPlayer>>) {
+    let (tx, rx) = mpsc::channel();
+    let (client, race) = RaceClient::join(address, name, move |message| { let _ = tx.send(message); }).unwrap();
+    (client, race, rx)
+}
+
+// Applies messages from the host until the race gets to where it's wanted
+fn wait_until(race: &mut Race, inbox: &Receiver<PrErr<ToPlayer>>, done: impl Fn(&Race) -> bool) {
+    while !done(race) {
+        let message = inbox.recv_timeout(Duration::from_secs(5)).expect("host went quiet").expect("host hung up");
+        race.apply(&message);
+    }
+}
+
+#[test]
+fn messages_round_trip() {
+    let feedback = GuessFeedback::from_pattern("GY..G").unwrap();
+    assert_eq!(feedback.pattern(), "GY..G");
+    assert!(GuessFeedback::from_pattern("GYXG").is_err());
+
+    for message in [ToHost::Join(String::from("Ada L")), ToHost::Row(feedback.clone())] {
+        assert_eq!(ToHost::parse(&message.to_string()), Ok(message));
+    }
+
+    let messages = [
+        ToPlayer::Welcome { id: 3, seed: 99, rules: Rules::new(6, 7).unwrap() },
+        ToPlayer::Joined { id: 0, name: String::from("Ada L") },
+        ToPlayer::Start,
+        ToPlayer::Row { id: 1, feedback },
+        ToPlayer::Left { id: 2 },
+        ToPlayer::Finished { winner: Some(1) },
+        ToPlayer::Finished { winner: None },
+        ToPlayer::Refused(String::from("The race has already started"))
+    ];
+    for message in messages {
+        assert_eq!(ToPlayer::parse(&message.to_string()), Ok(message));
+    }
+    assert!(ToPlayer::parse("ROW x GGGGG").is_err());
+}
+
+#[test]
+fn first_to_solve_wins_on_localhost() {
+    let (address, host) = host(2, Rules::default());
+    let (ada, mut ada_race, ada_inbox) = join(&address, "Ada");
+    let (bob, mut bob_race, bob_inbox) = join(&address, "Bob");
+
+    wait_until(&mut ada_race, &ada_inbox, |race| race.started && race.racers.len() == 2);
+    wait_until(&mut bob_race, &bob_inbox, |race| race.started && race.racers.len() == 2);
+    assert_eq!(ada_race.seed, bob_race.seed);
+
+    let mut ada_board = ada_race.board(Dictionary::bundled()).unwrap();
+    let mut bob_board = bob_race.board(Dictionary::bundled()).unwrap();
+    assert_eq!(ada_board.answer, bob_board.answer);
+
+    let wrong = ada_board.dictionary.accepted.words().iter().find(|w| **w != ada_board.answer).unwrap().clone();
+    ada.send_row(&ada_board.enter_word(&wrong).unwrap()).unwrap();
+    wait_until(&mut bob_race, &bob_inbox, |race| race.racer(ada_race.me).is_some_and(|ada| ada.rows.len() == 1));
+    let answer = bob_board.answer.clone();
+    bob.send_row(&bob_board.enter_word(&answer).unwrap()).unwrap();
+
+    assert_eq!(host.join().unwrap(), Ok(Some(String::from("Bob"))));
+
+    // Ada saw Bob's colours, but only the colours
+    wait_until(&mut ada_race, &ada_inbox, |race| race.over);
+    let bob_seen = ada_race.opponents().next().unwrap();
+    assert_eq!(bob_seen.name, "Bob");
+    assert_eq!(bob_seen.rows.len(), 1);
+    assert!(bob_seen.rows[0].is_solved());
+    assert_eq!(ada_race.winner, Some(bob_race.me));
+    assert_eq!(ada_race.summary(), "Bob won the race");
+    assert_eq!(ada_race.racer(ada_race.me).unwrap().rows.len(), 1);
+}
+
+#[test]
+fn race_with_no_winner() {
+    let (address, host) = host(1, Rules::new(5, 1).unwrap());
+    let (solo, mut race, inbox) = join(&address, "");
+    wait_until(&mut race, &inbox, |race| race.started);
+    assert_eq!(race.racer(race.me).unwrap().name, "Player 1");
+
+    let mut board = race.board(Dictionary::bundled()).unwrap();
+    let wrong = board.dictionary.accepted.words().iter().find(|w| **w != board.answer).unwrap().clone();
+    solo.send_row(&board.enter_word(&wrong).unwrap()).unwrap();
+
+    assert_eq!(host.join().unwrap(), Ok(None));
+    wait_until(&mut race, &inbox, |race| race.over);
+    assert_eq!(race.winner, None);
+}
+
+#[test]
+fn late_joiners_are_refused() {
+    let (address, _host) = host(1, Rules::default());
+    let (_first, mut race, inbox) = join(&address, "First");
+    wait_until(&mut race, &inbox, |race| race.started);
+
+    let late = RaceClient::join(&address, "Late", |_| ());
+    assert!(late.is_err_and(|e| e.contains("already started")));
+}
+
+#[test]
+fn launch_options_join_races() {
+    let args = |list: &[&str]| LaunchOptions::from_args(list.iter().map(|s| s.to_string()));
+    assert!(args(&["--race", "localhost:7878", "--daily"]).is_err());
+    assert!(args(&["--race", "localhost:7878"]).unwrap().board().is_err());
+
+    let (address, _host) = host(2, Rules::new(5, 8).unwrap());
+    let (_client, race, board) = args(&["--race", &address, "--name", "Ada"]).unwrap().race(|_| ()).unwrap();
+    assert_eq!(board.rules, Rules::new(5, 8).unwrap());
+    assert_eq!(race.seed, 42);
+}
@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use wordle_nova::{Board, Dictionary, Hint, Recommender, WordList};
+
+#[test]
+fn openers_beat_repeated_rare_letters() {
+    let words = WordList::bundled();
+    let recommender = Recommender::new(&words, words.words().iter().map(String::as_str).collect());
+
+    let slate = recommender.entropy("slate");
+    let crane = recommender.entropy("crane");
+    let fuzzy = recommender.entropy("fuzzy");
+    assert!(slate > 5.0 && crane > 5.0, "SLATE {:.3}, CRANE {:.3}", slate, crane);
+    assert!(fuzzy < slate && fuzzy < crane);
+    assert!(slate <= (243f64).log2());
+}
+
+#[test]
+fn top_prefers_candidates_on_ties() {
+    // With two candidates left every splitting guess is worth one bit
+    let guesses = WordList::parse("crane\ncrate\nzzzzz\nentry").unwrap();
+    let recommender = Recommender::new(&guesses, vec!["CRANE", "CRATE"]);
+
+    let top = recommender.top(4);
+    assert_eq!(top.iter().map(|r| r.word.as_str()).collect::<Vec<_>>(), vec!["CRANE", "CRATE", "ENTRY", "ZZZZZ"]);
+    assert!((top[0].entropy - 1.0).abs() < 1e-9);
+    assert!(top[0].candidate && !top[2].candidate);
+    assert_eq!(top[3].entropy, 0.0);
+
+    let plain = Recommender::new(&guesses, vec!["CRANE", "CRATE"]).prefer_candidates(false);
+    assert_eq!(plain.top(3)[2].word, "ENTRY");
+}
+
+#[test]
+fn board_recommendations_use_its_candidates() {
+    let mut board = Board::with_answer("CRANE").unwrap();
+    board.enter_word("SLATE").unwrap();
+    board.enter_word("CRATE").unwrap();
+
+    let recommender = Recommender::from_board(&board);
+    assert!(recommender.candidates().contains(&"CRANE"));
+    assert!(recommender.candidates().iter().all(|w| w.starts_with("CRA") && w.ends_with('E')));
+}
+
+#[test]
+fn opening_is_the_fresh_boards_best_guess() {
+    let words = Arc::new(WordList::parse("crane\ncrate\nslate\nfuzzy\nentry").unwrap());
+    let dictionary = Dictionary { answers: words.clone(), accepted: words };
+    let mut board = Board::with_answer("CRANE").unwrap().with_dictionary(dictionary.clone());
+
+    let best = Recommender::from_board(&board).top(1).pop();
+    assert_eq!(Recommender::opening(&dictionary), best);
+    assert_eq!(Recommender::opening(&dictionary), best);
+
+    board.hint().unwrap();
+    assert_eq!(board.hint(), Ok(Hint::Suggest(best.unwrap().word)));
+}
+
+#[test]
+fn quick_hints_do_not_wait_for_the_opening() {
+    let words = Arc::new(WordList::parse("abide\naside\namide\nbride").unwrap());
+    let dictionary = Dictionary { answers: words.clone(), accepted: words };
+    let mut board = Board::with_answer("ABIDE").unwrap().with_dictionary(dictionary.clone());
+    assert_eq!(Recommender::ready_opening(&dictionary), None);
+
+    board.quick_hint().unwrap();
+    assert_eq!(board.quick_hint(), Ok(Hint::Reveal { position: 0, letter: 'A' }));
+
+    let best = Recommender::opening(&dictionary);
+    assert!(best.is_some());
+    assert_eq!(Recommender::ready_opening(&dictionary), best);
+}
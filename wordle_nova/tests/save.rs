@@ -0,0 +1,67 @@
+mod common;
+
+use common::play;
+use wordle_nova::{Board, BoardStatus, Dictionary, GameMode, LaunchOptions, Rules};
+
+fn game(guesses: &[&str]) -> Board {
+    let mut board = Board::seeded(Dictionary::bundled(), Rules::default(), 42).unwrap();
+    board.gui_theme_no = 3;
+    play(&mut board, guesses);
+    board.hard_mode = true;
+
+    board
+}
+
+#[test]
+fn saved_board_comes_back_the_same() {
+    let mut board = game(&["SLATE", "CRONY"]);
+    board.hint().unwrap();
+
+    let text = board.to_save();
+    assert!(!text.contains(&board.answer));
+
+    let resumed = Board::from_save(&text, Dictionary::bundled()).unwrap();
+    assert_eq!(resumed, board);
+}
+
+#[test]
+fn finished_boards_keep_their_status() {
+    let board = game(&["SLATE"; 6]);
+    let resumed = Board::from_save(&board.to_save(), Dictionary::bundled()).unwrap();
+
+    assert_eq!(resumed.status, BoardStatus::Lose);
+    assert_eq!(resumed, board);
+}
+
+#[test]
+fn tampered_saves_are_refused() {
+    let text = game(&["SLATE"]).to_save();
+    let pattern = text.lines().find(|l| l.starts_with("guess")).unwrap().split_whitespace().last().unwrap().to_string();
+
+    assert!(Board::from_save(&text.replace(&pattern, "GGGGG"), Dictionary::bundled()).is_err());
+    assert!(Board::from_save(&text.replace("ongoing 0", "ongoing 1"), Dictionary::bundled()).is_err());
+    assert!(Board::from_save(&text.replace("version = 1", "version = 9"), Dictionary::bundled()).is_err());
+
+    let mut board = game(&["SLATE"]);
+    board.hint().unwrap();
+    let text = board.to_save();
+    assert!(Board::from_save(&text, Dictionary::bundled()).is_ok());
+    assert!(Board::from_save(&text.replace(" after 1", ""), Dictionary::bundled()).is_err());
+    assert!(Board::from_save(&text.replace(" after 1", " after 2"), Dictionary::bundled()).is_err());
+}
+
+#[test]
+fn launch_resumes_only_the_game_asked_for() {
+    let saved = game(&["SLATE"]);
+    let options = || LaunchOptions::from_args(std::iter::empty()).unwrap();
+
+    assert_eq!(options().resume(Some(saved.clone())).unwrap(), saved);
+
+    // Finished game: a new one, with the same settings
+    let fresh = options().resume(Some(game(&["SLATE"; 6]))).unwrap();
+    assert_eq!(fresh.status, BoardStatus::NotStarted);
+    assert_eq!((fresh.hard_mode, fresh.gui_theme_no), (true, 3));
+
+    let daily = LaunchOptions::from_args(["--daily".to_string()].into_iter()).unwrap();
+    assert!(matches!(daily.resume(Some(saved)).unwrap().mode, GameMode::Daily(_)));
+}
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use wordle_nova::{Board, BoardStatus, GuessFeedback, TileFeedback, TileState, WordList};
+
+// (answer, guess, expected row: G right place, Y wrong place, . absent)
+const CASES: &[(&str, &str, &str)] = &[
+    ("ABIDE", "SPEED", "..Y.Y"),
+    ("THOSE", "EERIE", "....G"),
+    ("CRANE", "EERIE", "..Y.G"),
+    ("EERIE", "EERIE", "GGGGG"),
+    ("EERIE", "GEESE", ".GY.G"),
+    ("LLAMA", "ALLOY", "YGY.."),
+    ("LLAMA", "LABEL", "GY..Y"),
+    ("LLAMA", "MAMMA", ".Y.GG"),
+    ("ABBEY", "BOBBY", "Y.G.G"),
+    ("ABBEY", "KEBAB", ".YGYY"),
+    ("ABBEY", "BABES", "YYGG."),
+    ("SPEED", "ERASE", "Y..YY"),
+    ("SPEED", "EEEEE", "..GG."),
+    ("SPEED", "SPEED", "GGGGG"),
+];
+
+// Every guess in the table is accepted, dictionary word or not
+fn score(answer: &str, guess: &str) -> (Board, GuessFeedback) {
+    let guesses: Vec<&str> = CASES.iter().map(|(_, guess, _)| *guess).collect();
+    let mut board = Board::with_answer(answer).unwrap().with_accepted(Arc::new(WordList::parse(&guesses.join("\n")).unwrap()));
+    for c in guess.chars() {
+        board.push_letter(c);
+    }
+
+    let correction = board.submit_row().expect("a full row is always scored");
+    (board, correction)
+}
+
+fn pattern(feedback: &GuessFeedback) -> String {
+    feedback.iter().map(|t| match t.state {
+        TileState::Correct  => 'G',
+        TileState::Present  => 'Y',
+        TileState::Absent   => '.',
+        _                   => '?'
+    }).collect()
+}
+
+#[test]
+fn duplicate_letters_follow_two_pass_scoring() {
+    for (answer, guess, expected) in CASES {
+        let (_, correction) = score(answer, guess);
+        assert_eq!(pattern(&correction), *expected, "{} against {}", guess, answer);
+    }
+}
+
+#[test]
+fn answer_count_counts_copies_in_the_answer() {
+    // LLAMA holds two Ls and two As
+    let (_, correction) = score("LLAMA", "ALLOY");
+    assert_eq!(correction.tiles(), &[
+        TileFeedback::new(TileState::Present, 2),
+        TileFeedback::new(TileState::Correct, 2),
+        TileFeedback::new(TileState::Present, 2),
+        TileFeedback::new(TileState::Absent, 0),
+        TileFeedback::new(TileState::Absent, 0)
+    ]);
+}
+
+#[test]
+fn letter_hint_keeps_best_state_seen() {
+    let (mut board, _) = score("ABIDE", "SPEED");
+    assert_eq!(board.gui_letter_hint[(b'E' - b'A') as usize], TileState::Present);
+    assert_eq!(board.gui_letter_hint[(b'S' - b'A') as usize], TileState::Absent);
+
+    for c in "ABIDE".chars() {
+        board.push_letter(c);
+    }
+    board.submit_row().unwrap();
+    assert_eq!(board.gui_letter_hint[(b'E' - b'A') as usize], TileState::Correct);
+    assert_eq!(board.status, BoardStatus::Win(1));
+}
@@ -0,0 +1,33 @@
+mod common;
+
+use common::played;
+use wordle_nova::{Board, GameMode, Rules};
+
+#[test]
+fn grid_has_no_letters() {
+    let board = played("CRANE", &["SLATE", "TRACE", "CRANE"]);
+
+    assert_eq!(board.share().unwrap(), "Wordle_Beta 3/6\n\n⬛⬛🟩⬛🟩\n⬛🟩🟩🟨🟩\n🟩🟩🟩🟩🟩");
+}
+
+#[test]
+fn header_marks_mode_and_settings() {
+    let mut board = played("CRANE", &["SLATE"; 6]);
+    board.mode = GameMode::Daily(42);
+    board.hard_mode = true;
+    assert!(board.share().unwrap().starts_with("Wordle_Beta 42 X/6*\n"));
+
+    let mut board = Board::new("CRANE", Rules::default()).unwrap();
+    assert!(board.share().is_err());
+    board.hint().unwrap();
+    board.enter_word("CRANE").unwrap();
+    assert!(board.share().unwrap().starts_with("Wordle_Beta 1/6 💡\n"));
+}
+
+#[test]
+fn high_contrast_uses_orange_and_blue() {
+    let mut board = played("CRANE", &["TRACE", "CRANE"]);
+    board.high_contrast = true;
+
+    assert!(board.share().unwrap().ends_with("⬛🟧🟧🟦🟧\n🟧🟧🟧🟧🟧"));
+}
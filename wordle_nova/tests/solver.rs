@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+mod common;
+
+use common::play;
+use wordle_nova::{Board, GuessFeedback, Hint, Solver, WordList};
+
+#[test]
+fn candidates_reproduce_every_row() {
+    let mut board = Board::with_answer("ABBEY").unwrap();
+    play(&mut board, &["BOBBY", "KEBAB"]);
+
+    let candidates = Solver::from_board(&board).candidates();
+    assert!(candidates.contains(&"ABBEY"));
+
+    for word in &candidates {
+        let answer: Vec<char> = word.chars().collect();
+        for row in 0..2 {
+            assert_eq!(GuessFeedback::score(&board.guesses[row], &answer).unwrap(), board.correction[row], "{}", word);
+        }
+    }
+}
+
+#[test]
+fn answer_count_separates_repeated_letters() {
+    // CRANE against EERIE and THREE gives the same colours, but EERIE holds three Es
+    let words = Arc::new(WordList::parse("eerie\nthree\nspeed").unwrap());
+    let mut board = Board::with_answer("EERIE").unwrap();
+    board.dictionary.answers = words.clone();
+    play(&mut board, &["CRANE"]);
+
+    assert_eq!(board.correction[0].pattern(), ".Y..G");
+    assert_eq!(Solver::from_board(&board).candidates(), vec!["EERIE"]);
+}
+
+#[test]
+fn pattern_rows_match_on_colour_alone() {
+    // A row read back from a pattern has no letter counts to check
+    let words = WordList::parse("crane\ncrate\nslate").unwrap();
+    let mut solver = Solver::new(&words);
+    solver.add("crane", &GuessFeedback::from_pattern("GGGGG").unwrap());
+    assert_eq!(solver.candidates(), vec!["CRANE"]);
+
+    let mut solver = Solver::new(&words);
+    solver.add("slate", &GuessFeedback::from_pattern("..GGG").unwrap());
+    assert_eq!(solver.candidates(), vec!["CRATE"]);
+}
+
+#[test]
+fn duplicate_guess_letters_cap_the_count() {
+    // SPEED against ABIDE: one E is yellow, the other absent, so exactly one E
+    let words = WordList::parse("abide\nelide\nbride\nsided").unwrap();
+    let mut solver = Solver::new(&words);
+    let feedback = GuessFeedback::score(&['S', 'P', 'E', 'E', 'D'], &['A', 'B', 'I', 'D', 'E']).unwrap();
+    solver.add("speed", &feedback);
+
+    assert_eq!(solver.candidates(), vec!["ABIDE", "BRIDE"]);
+}
+
+#[test]
+fn unscored_board_keeps_everything() {
+    let board = Board::with_answer("CRANE").unwrap();
+    assert_eq!(Solver::from_board(&board).candidates().len(), board.dictionary.answers.len());
+}
+
+#[test]
+fn hints_escalate_and_are_recorded() {
+    let mut board = Board::with_answer("CRANE").unwrap();
+    play(&mut board, &["CRATE"]);
+    let left = Solver::from_board(&board).candidates().len();
+
+    assert_eq!(board.hint().unwrap(), Hint::Candidates(left));
+    assert!(matches!(board.hint().unwrap(), Hint::Suggest(_)));
+
+    // C, R, A and E are already green, so the reveal skips them
+    let reveal = board.hint().unwrap();
+    assert_eq!(reveal, Hint::Reveal { position: 3, letter: 'N' });
+    assert_eq!(reveal.to_string(), "4th letter is N");
+    assert_eq!(board.hints.len(), 3);
+
+    play(&mut board, &["CRANE"]);
+    assert!(board.hint().is_err());
+    assert_eq!(board.hints.len(), 3);
+}
@@ -0,0 +1,44 @@
+use std::collections::HashSet;
+
+use wordle_nova::{Board, Dictionary, Rules, WordList, WordSource};
+
+#[test]
+fn same_seed_rebuilds_the_same_board() {
+    let a = Board::seeded(Dictionary::bundled(), Rules::default(), 2024).unwrap();
+    let b = Board::seeded(Dictionary::bundled(), Rules::default(), 2024).unwrap();
+
+    assert_eq!(a.answer, b.answer);
+    assert_eq!(a.seed, Some(2024));
+}
+
+#[test]
+fn reported_seed_reproduces_a_sourced_game() {
+    let mut source = WordSource::seeded(7);
+    let first = Board::from_source(Dictionary::bundled(), Rules::default(), &mut source).unwrap();
+    let second = Board::from_source(Dictionary::bundled(), Rules::default(), &mut source).unwrap();
+
+    for board in [first, second] {
+        let again = Board::seeded(Dictionary::bundled(), Rules::default(), board.seed.unwrap()).unwrap();
+        assert_eq!(board.answer, again.answer);
+    }
+}
+
+#[test]
+fn every_word_can_be_picked() {
+    let answers = WordList::parse("cigar\nrebut\nsissy\nhumph\nawake").unwrap();
+    let picked: HashSet<String> = (0..200)
+        .map(|seed| WordSource::pick(&answers, seed).unwrap())
+        .collect();
+
+    assert_eq!(picked.len(), answers.len());
+}
+
+#[test]
+fn same_seed_draws_the_same_sample() {
+    let answers = WordList::parse("cigar\nrebut\nsissy\nhumph\nawake").unwrap();
+    let sample = WordSource::sample(&answers, 3, 11);
+
+    assert_eq!(sample, WordSource::sample(&answers, 3, 11));
+    assert_eq!(sample.iter().collect::<HashSet<_>>().len(), 3);
+    assert_eq!(WordSource::sample(&answers, 10, 11).len(), answers.len());
+}
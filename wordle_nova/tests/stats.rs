@@ -0,0 +1,47 @@
+mod common;
+
+use common::played;
+use wordle_nova::{Board, Rules, Stats};
+
+#[test]
+fn streaks_and_distribution_follow_results() {
+    let mut stats = Stats::default();
+
+    assert!(!stats.record(&played("CRANE", &["SLATE"])));
+    assert!(stats.record(&played("CRANE", &["SLATE", "CRANE"])));
+    assert!(stats.record(&played("CRANE", &["CRANE"])));
+    assert!(stats.record(&played("CRANE", &["SLATE"; 6])));
+    assert!(stats.record(&played("CRANE", &["CRANE"])));
+
+    assert_eq!((stats.played, stats.wins), (4, 3));
+    assert_eq!((stats.current_streak, stats.max_streak), (1, 2));
+    assert_eq!(stats.win_percent(), 75);
+    assert_eq!((stats.won_in(1), stats.won_in(2), stats.won_in(6)), (2, 1, 0));
+}
+
+#[test]
+fn hinted_games_are_counted() {
+    let mut stats = Stats::default();
+    let mut board = Board::new("CRANE", Rules::default()).unwrap();
+    board.hint().unwrap();
+    board.enter_word("CRANE").unwrap();
+
+    stats.record(&board);
+    assert_eq!(stats.hinted, 1);
+}
+
+#[test]
+fn file_round_trip() {
+    let mut stats = Stats::default();
+    stats.record(&played("CRANE", &["SLATE", "TRACE", "CRANE"]));
+
+    assert_eq!(Stats::parse(&stats.to_text()), Ok(stats.clone()));
+    assert!(Stats::parse("played = 3").is_err());
+    assert!(Stats::parse("version = 99\nplayed = 3").is_err());
+
+    let path = std::env::temp_dir().join(format!("wordle_nova_stats_{}.txt", std::process::id()));
+    assert_eq!(Stats::load(&path), Ok(Stats::default()));
+    stats.save(&path).unwrap();
+    assert_eq!(Stats::load(&path), Ok(stats));
+    std::fs::remove_file(&path).unwrap();
+}
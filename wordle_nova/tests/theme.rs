@@ -0,0 +1,11 @@
+use wordle_nova::{tile_colour, TileState, CONTRAST_CORRECT, CONTRAST_PRESENT, THEMES};
+
+#[test]
+fn contrast_colours_replace_present_and_correct_only() {
+    for (theme, palette) in THEMES.iter().enumerate() {
+        assert_eq!(tile_colour(theme, false, TileState::Correct), palette[3]);
+        assert_eq!(tile_colour(theme, true, TileState::Correct), CONTRAST_CORRECT);
+        assert_eq!(tile_colour(theme, true, TileState::Present), CONTRAST_PRESENT);
+        assert_eq!(tile_colour(theme, true, TileState::Absent), tile_colour(theme, false, TileState::Absent));
+    }
+}